@@ -0,0 +1,68 @@
+use crate::common::{ChunkBitCounter, ChunkType, TotalBitCounter};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BitCount<Chunk: ChunkType> {
+    chunk_offset: usize,
+    bit_offset: Chunk::BitCounter,
+}
+
+impl<Chunk: ChunkType> BitCount<Chunk> {
+    pub fn new(chunk_offset: usize, bit_offset: Chunk::BitCounter) -> Option<Self> {
+        bit_offset.is_valid().then_some(BitCount {
+            chunk_offset,
+            bit_offset,
+        })
+    }
+    pub fn chunk_offset(self) -> usize {
+        self.chunk_offset
+    }
+    pub fn bit_offset(self) -> Chunk::BitCounter {
+        self.bit_offset
+    }
+}
+
+impl<Chunk: ChunkType> TotalBitCounter<Chunk> for BitCount<Chunk> {
+    const ZERO: Self = BitCount {
+        chunk_offset: 0,
+        bit_offset: Chunk::BitCounter::ZERO,
+    };
+    fn from_chunk_count(count: usize) -> Option<Self> {
+        Some(BitCount {
+            chunk_offset: count,
+            bit_offset: Chunk::BitCounter::ZERO,
+        })
+    }
+    fn checked_add(self, rhs: Chunk::BitCounter) -> Option<Self> {
+        let (bit_offset, carry) = self.bit_offset.overflowing_add(rhs);
+        Some(BitCount {
+            chunk_offset: self.chunk_offset.checked_add(carry as usize)?,
+            bit_offset,
+        })
+    }
+    fn split(self) -> (usize, Chunk::BitCounter) {
+        (self.chunk_offset, self.bit_offset)
+    }
+}
+
+macro_rules! impl_total_bit_counter {
+    ($($total:ty),*) => {$(
+        impl<Chunk: ChunkType<BitCounter = u32>> TotalBitCounter<Chunk> for $total {
+            const ZERO: Self = 0;
+            fn from_chunk_count(count: usize) -> Option<Self> {
+                Self::try_from(count).ok()?.checked_mul(Chunk::BITS as Self)
+            }
+            fn checked_add(self, rhs: u32) -> Option<Self> {
+                <$total>::checked_add(self, rhs as Self)
+            }
+            fn split(self) -> (usize, u32) {
+                let bits = Chunk::BITS as Self;
+                (
+                    usize::try_from(self / bits).unwrap_or(usize::MAX),
+                    (self % bits) as u32,
+                )
+            }
+        }
+    )*};
+}
+
+impl_total_bit_counter!(u32, u64, usize);
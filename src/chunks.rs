@@ -0,0 +1,373 @@
+#[allow(unused_macros)]
+macro_rules! impl_chunk_type {
+    (
+        $chunk:ty,
+        $signed:ty,
+        |$l:ident, $r:ident, $add:ident| $carrying_mul:block,
+        |$n:ident, $d:ident, $carry:ident| $carrying_div_rem:block
+    ) => {
+        impl crate::ChunkType for $chunk {
+            const BITS: u32 = <$chunk>::BITS;
+            const MAX: Self = <$chunk>::MAX;
+            const ONE: Self = 1;
+            const ZERO: Self = 0;
+            const LEADING_ONE: Self = 1 << (<$chunk>::BITS - 1);
+            const LEADING_ZERO: Self = <$chunk>::MAX >> 1;
+            type BitCounter = u32;
+            fn count_ones(self) -> u32 {
+                <$chunk>::count_ones(self)
+            }
+            fn count_zeros(self) -> u32 {
+                <$chunk>::count_zeros(self)
+            }
+            fn leading_ones(self) -> u32 {
+                <$chunk>::leading_ones(self)
+            }
+            fn leading_zeros(self) -> u32 {
+                <$chunk>::leading_zeros(self)
+            }
+            fn trailing_ones(self) -> u32 {
+                <$chunk>::trailing_ones(self)
+            }
+            fn trailing_zeros(self) -> u32 {
+                <$chunk>::trailing_zeros(self)
+            }
+            fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool) {
+                let (result, carry_1) = self.overflowing_add(rhs);
+                let (result, carry_2) = result.overflowing_add(carry as Self);
+                (result, carry_1 | carry_2)
+            }
+            fn carrying_add_as_signed(self, rhs: Self, carry: bool) -> (Self, bool) {
+                let (result, overflow_1) = (self as $signed).overflowing_add(rhs as $signed);
+                let (result, overflow_2) = result.overflowing_add(carry as $signed);
+                (result as Self, overflow_1 != overflow_2)
+            }
+            fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool) {
+                let (result, borrow_1) = self.overflowing_sub(rhs);
+                let (result, borrow_2) = result.overflowing_sub(borrow as Self);
+                (result, borrow_1 | borrow_2)
+            }
+            fn borrowing_sub_as_signed(self, rhs: Self, borrow: bool) -> (Self, bool) {
+                let (result, overflow_1) = (self as $signed).overflowing_sub(rhs as $signed);
+                let (result, overflow_2) = result.overflowing_sub(borrow as $signed);
+                (result as Self, overflow_1 != overflow_2)
+            }
+            fn add_carry(self, carry: bool) -> Option<Self> {
+                self.checked_add(carry as Self)
+            }
+            fn shl_chunk_full(self, shamt: u32, infill: Self) -> (Self, Self) {
+                (
+                    self << shamt | infill,
+                    self.checked_shr(<$chunk>::BITS - shamt).unwrap_or(0),
+                )
+            }
+            fn shr_chunk_full(self, shamt: u32, infill: Self) -> (Self, Self) {
+                (
+                    self >> shamt | infill,
+                    self.checked_shl(<$chunk>::BITS - shamt).unwrap_or(0),
+                )
+            }
+            fn cmp_as_signed(self, other: Self) -> core::cmp::Ordering {
+                (self as $signed).cmp(&(other as $signed))
+            }
+            fn reverse_bits(self) -> Self {
+                <$chunk>::reverse_bits(self)
+            }
+            fn swap_bytes(self) -> Self {
+                <$chunk>::swap_bytes(self)
+            }
+            fn carrying_mul(self, rhs: Self, add: Self) -> (Self, Self) {
+                let ($l, $r, $add) = (self, rhs, add);
+                $carrying_mul
+            }
+            fn carrying_div_rem(self, rhs: Self, carry: Self) -> (Self, Self) {
+                let ($n, $d, $carry) = (self, rhs, carry);
+                $carrying_div_rem
+            }
+            fn wrapping_from_u128(value: u128) -> Self {
+                value as Self
+            }
+            fn wrapping_to_u128(self) -> u128 {
+                self as u128
+            }
+        }
+
+        impl crate::ChunkBitCounter<$chunk> for u32 {
+            const ZERO: Self = 0;
+            fn is_valid(self) -> bool {
+                self < <$chunk>::BITS
+            }
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let result = self + rhs;
+                if result >= <$chunk>::BITS {
+                    (result - <$chunk>::BITS, true)
+                } else {
+                    (result, false)
+                }
+            }
+        }
+    };
+    ($chunk:ty, $signed:ty, $wide:ty) => {
+        impl_chunk_type!(
+            $chunk,
+            $signed,
+            |l, r, add| {
+                let result = l as $wide * r as $wide + add as $wide;
+                (result as $chunk, (result >> <$chunk>::BITS) as $chunk)
+            },
+            |n, d, carry| {
+                let n = (carry as $wide) << <$chunk>::BITS | n as $wide;
+                ((n / d as $wide) as $chunk, (n % d as $wide) as $chunk)
+            }
+        );
+    };
+}
+
+#[cfg(feature = "chunks_8")]
+impl_chunk_type!(u8, i8, u16);
+#[cfg(feature = "chunks_16")]
+impl_chunk_type!(u16, i16, u32);
+#[cfg(feature = "chunks_32")]
+impl_chunk_type!(u32, i32, u64);
+#[cfg(feature = "chunks_64")]
+impl_chunk_type!(u64, i64, u128);
+#[cfg(feature = "chunks_128")]
+impl_chunk_type!(
+    u128,
+    i128,
+    |l, r, add| {
+        const HALF: u32 = u64::BITS;
+        let (l_lo, l_hi) = (l as u64 as u128, l >> HALF);
+        let (r_lo, r_hi) = (r as u64 as u128, r >> HALF);
+        let lo_lo = l_lo * r_lo;
+        let lo_hi = l_lo * r_hi;
+        let hi_lo = l_hi * r_lo;
+        let mid = (lo_lo >> HALF) + (lo_hi as u64 as u128) + (hi_lo as u64 as u128);
+        let lo = (lo_lo as u64 as u128) | mid << HALF;
+        let hi = l_hi * r_hi + (lo_hi >> HALF) + (hi_lo >> HALF) + (mid >> HALF);
+        let (lo, carry) = lo.overflowing_add(add);
+        (lo, hi + carry as u128)
+    },
+    |n, d, carry| {
+        const HALF: u32 = u64::BITS;
+        const BASE: u128 = 1 << HALF;
+        let shift = d.leading_zeros();
+        let d = d << shift;
+        let (d_lo, d_hi) = (d as u64 as u128, d >> HALF);
+        let n_hi = carry << shift | n.checked_shr(u128::BITS - shift).unwrap_or(0);
+        let n = n << shift;
+        let (n_lo_lo, n_lo_hi) = (n as u64 as u128, n >> HALF);
+        let estimate = |n_hi: u128, n_lo: u128| {
+            let (mut q, mut r) = (n_hi / d_hi, n_hi % d_hi);
+            while q >= BASE || q * d_lo > (r << HALF | n_lo) {
+                q -= 1;
+                r += d_hi;
+                if r >= BASE {
+                    break;
+                }
+            }
+            (q, (n_hi << HALF | n_lo).wrapping_sub(q.wrapping_mul(d)))
+        };
+        let (q_hi, n_hi) = estimate(n_hi, n_lo_hi);
+        let (q_lo, r) = estimate(n_hi, n_lo_lo);
+        (q_hi << HALF | q_lo, r >> shift)
+    }
+);
+
+#[cfg(all(
+    test,
+    any(
+        feature = "chunks_8",
+        feature = "chunks_16",
+        feature = "chunks_32",
+        feature = "chunks_64",
+        feature = "chunks_128"
+    )
+))]
+mod tests {
+    use crate::{common::tests::next, ChunkType};
+
+    macro_rules! test_shifts {
+        ($chunk:ty) => {
+            #[test]
+            fn shifts() {
+                let mut state = 0x2545_f491_4f6c_dd1d;
+                for _ in 0..10_000 {
+                    let (x, infill) = (next(&mut state) as $chunk, next(&mut state) as $chunk);
+                    let low = infill & 1;
+                    let high = infill & (1 << (<$chunk>::BITS - 1));
+                    assert_eq!(x.shl_chunk_full(0, 0), (x, 0));
+                    assert_eq!(x.shr_chunk_full(0, 0), (x, 0));
+                    assert_eq!(
+                        x.shl_chunk_full(<$chunk>::BITS - 1, low),
+                        (x << (<$chunk>::BITS - 1) | low, x >> 1)
+                    );
+                    assert_eq!(
+                        x.shr_chunk_full(<$chunk>::BITS - 1, high),
+                        (x >> (<$chunk>::BITS - 1) | high, x << 1)
+                    );
+                }
+            }
+        };
+    }
+
+    macro_rules! test_chunk {
+        ($feature:literal, $module:ident, $chunk:ty, $wide:ty) => {
+            #[cfg(feature = $feature)]
+            mod $module {
+                use super::*;
+
+                test_shifts!($chunk);
+
+                #[test]
+                fn carrying_add_borrowing_sub() {
+                    let mut state = 0x9e37_79b9_7f4a_7c15;
+                    for _ in 0..10_000 {
+                        let (a, b) = (next(&mut state) as $chunk, next(&mut state) as $chunk);
+                        for carry in [false, true] {
+                            let sum = a as $wide + b as $wide + carry as $wide;
+                            assert_eq!(
+                                a.carrying_add(b, carry),
+                                (sum as $chunk, sum >> <$chunk>::BITS != 0)
+                            );
+                            let difference = (a as $wide)
+                                .wrapping_sub(b as $wide)
+                                .wrapping_sub(carry as $wide);
+                            assert_eq!(
+                                a.borrowing_sub(b, carry),
+                                (
+                                    difference as $chunk,
+                                    (a as $wide) < b as $wide + carry as $wide
+                                )
+                            );
+                        }
+                    }
+                }
+
+                #[test]
+                fn carrying_mul() {
+                    let mut state = 0xd1b5_4a32_d192_ed03;
+                    for _ in 0..10_000 {
+                        let (a, b, c) = (
+                            next(&mut state) as $chunk,
+                            next(&mut state) as $chunk,
+                            next(&mut state) as $chunk,
+                        );
+                        let product = a as $wide * b as $wide + c as $wide;
+                        assert_eq!(
+                            a.carrying_mul(b, c),
+                            (product as $chunk, (product >> <$chunk>::BITS) as $chunk)
+                        );
+                    }
+                    let max = <$chunk>::MAX;
+                    assert_eq!(max.carrying_mul(max, max), (0, max));
+                }
+
+                #[test]
+                fn carrying_div_rem() {
+                    let mut state = 0x8cb9_2ba7_2f3d_8dd7;
+                    for _ in 0..10_000 {
+                        let (n, d) = (next(&mut state) as $chunk, next(&mut state) as $chunk | 1);
+                        let carry = next(&mut state) as $chunk % d;
+                        let dividend = (carry as $wide) << <$chunk>::BITS | n as $wide;
+                        assert_eq!(
+                            n.carrying_div_rem(d, carry),
+                            (
+                                (dividend / d as $wide) as $chunk,
+                                (dividend % d as $wide) as $chunk
+                            )
+                        );
+                    }
+                    let max = <$chunk>::MAX;
+                    assert_eq!(max.carrying_div_rem(max, max - 1), (max, max - 1));
+                    assert_eq!(max.carrying_div_rem(1, 0), (max, 0));
+                }
+            }
+        };
+    }
+
+    test_chunk!("chunks_8", chunk_u8, u8, u16);
+    test_chunk!("chunks_16", chunk_u16, u16, u32);
+    test_chunk!("chunks_32", chunk_u32, u32, u64);
+    test_chunk!("chunks_64", chunk_u64, u64, u128);
+
+    #[cfg(feature = "chunks_128")]
+    mod chunk_u128 {
+        use super::*;
+
+        test_shifts!(u128);
+
+        fn reference_mul(a: u128, b: u128, c: u128) -> (u128, u128) {
+            let limbs = |x: u128| [x as u64, (x >> 64) as u64];
+            let (a, b) = (limbs(a), limbs(b));
+            let mut result = [0u64; 4];
+            for (i, &a) in a.iter().enumerate() {
+                let mut carry = 0;
+                for (j, &b) in b.iter().enumerate() {
+                    let t = a as u128 * b as u128 + result[i + j] as u128 + carry;
+                    result[i + j] = t as u64;
+                    carry = t >> 64;
+                }
+                result[i + 2] = carry as u64;
+            }
+            let lo = (result[1] as u128) << 64 | result[0] as u128;
+            let hi = (result[3] as u128) << 64 | result[2] as u128;
+            let (lo, overflow) = lo.overflowing_add(c);
+            (lo, hi + overflow as u128)
+        }
+
+        #[test]
+        fn carrying_add_borrowing_sub() {
+            let mut state = 0x9e37_79b9_7f4a_7c15;
+            for _ in 0..10_000 {
+                let (a, b) = (next(&mut state), next(&mut state));
+                for carry in [false, true] {
+                    let sum = a
+                        .checked_add(b)
+                        .and_then(|sum| sum.checked_add(carry as u128));
+                    assert_eq!(
+                        a.carrying_add(b, carry),
+                        (a.wrapping_add(b).wrapping_add(carry as u128), sum.is_none())
+                    );
+                    let difference = a.checked_sub(b).and_then(|d| d.checked_sub(carry as u128));
+                    assert_eq!(
+                        a.borrowing_sub(b, carry),
+                        (
+                            a.wrapping_sub(b).wrapping_sub(carry as u128),
+                            difference.is_none()
+                        )
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn carrying_mul() {
+            let mut state = 0xd1b5_4a32_d192_ed03;
+            for _ in 0..10_000 {
+                let (a, b, c) = (next(&mut state), next(&mut state), next(&mut state));
+                assert_eq!(a.carrying_mul(b, c), reference_mul(a, b, c));
+            }
+            assert_eq!(u128::MAX.carrying_mul(u128::MAX, u128::MAX), (0, u128::MAX));
+            assert_eq!((1u128 << 64).carrying_mul(1 << 64, 0), (0, 1));
+        }
+
+        #[test]
+        fn carrying_div_rem() {
+            let mut state = 0x8cb9_2ba7_2f3d_8dd7;
+            for _ in 0..10_000 {
+                let (n, d) = (next(&mut state), next(&mut state) | 1);
+                let carry = next(&mut state) % d;
+                let (q, r) = n.carrying_div_rem(d, carry);
+                assert!(r < d);
+                let (lo, hi) = q.carrying_mul(d, r);
+                assert_eq!((lo, hi), (n, carry));
+                assert_eq!(reference_mul(q, d, r), (n, carry));
+            }
+            let max = u128::MAX;
+            assert_eq!(max.carrying_div_rem(max, max - 1), (max, max - 1));
+            assert_eq!(max.carrying_div_rem(1, 0), (max, 0));
+            assert_eq!(0u128.carrying_div_rem(1 << 64, 1), (1 << 64, 0));
+        }
+    }
+}
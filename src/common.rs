@@ -1,8 +1,27 @@
-use core::{cmp::Ordering, mem::replace, ops::BitOrAssign};
+use core::{
+    cmp::Ordering,
+    iter::zip,
+    mem::replace,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
 
 use discard_while::discard_while;
 
-pub trait ChunkType: Sized + Copy + BitOrAssign + Eq + Ord {
+use crate::bit_count::BitCount;
+
+pub trait ChunkType:
+    Sized
+    + Copy
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + BitXor<Output = Self>
+    + BitXorAssign
+    + Not<Output = Self>
+    + Eq
+    + Ord
+{
     const BITS: Self::BitCounter;
     const MAX: Self;
     const ONE: Self;
@@ -18,17 +37,24 @@ pub trait ChunkType: Sized + Copy + BitOrAssign + Eq + Ord {
     fn trailing_zeros(self) -> Self::BitCounter;
     fn carrying_add(self, rhs: Self, carry: bool) -> (Self, bool);
     fn carrying_add_as_signed(self, rhs: Self, carry: bool) -> (Self, bool);
+    fn borrowing_sub(self, rhs: Self, borrow: bool) -> (Self, bool);
+    fn borrowing_sub_as_signed(self, rhs: Self, borrow: bool) -> (Self, bool);
     fn add_carry(self, carry: bool) -> Option<Self>;
     fn shl_chunk_full(self, shamt: Self::BitCounter, infill: Self) -> (Self, Self);
     fn shr_chunk_full(self, shamt: Self::BitCounter, infill: Self) -> (Self, Self);
     fn cmp_as_signed(self, other: Self) -> Ordering;
     fn reverse_bits(self) -> Self;
+    fn swap_bytes(self) -> Self;
     fn carrying_mul(self, rhs: Self, add: Self) -> (Self, Self);
+    fn carrying_div_rem(self, rhs: Self, carry: Self) -> (Self, Self);
+    fn wrapping_from_u128(value: u128) -> Self;
+    fn wrapping_to_u128(self) -> u128;
 }
 
 pub trait ChunkBitCounter<Chunk: ChunkType>: Copy + PartialEq {
     const ZERO: Self;
     fn is_valid(self) -> bool;
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
 }
 
 pub trait TotalBitCounter<Chunk: ChunkType>: Sized {
@@ -38,6 +64,85 @@ pub trait TotalBitCounter<Chunk: ChunkType>: Sized {
     fn split(self) -> (usize, Chunk::BitCounter);
 }
 
+macro_rules! impl_bitwise_ops {
+    ($type:ident) => {
+        impl_bitwise_ops!($type, BitAnd, bitand, BitAndAssign, bitand_assign, &);
+        impl_bitwise_ops!($type, BitOr, bitor, BitOrAssign, bitor_assign, |);
+        impl_bitwise_ops!($type, BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+        impl<const W: usize, Chunk: ChunkType> core::ops::Not for $type<W, Chunk> {
+            type Output = Self;
+            fn not(mut self) -> Self {
+                for chunk in &mut self.chunks {
+                    *chunk = !*chunk;
+                }
+                self
+            }
+        }
+
+        impl<const W: usize, Chunk: ChunkType> core::ops::Not for &$type<W, Chunk> {
+            type Output = $type<W, Chunk>;
+            fn not(self) -> $type<W, Chunk> {
+                !$type { chunks: self.chunks }
+            }
+        }
+    };
+    ($type:ident, $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl<const W: usize, Chunk: ChunkType> core::ops::$assign_trait<&$type<W, Chunk>>
+            for $type<W, Chunk>
+        {
+            fn $assign_method(&mut self, rhs: &Self) {
+                for (chunk_l, &chunk_r) in core::iter::zip(&mut self.chunks, &rhs.chunks) {
+                    *chunk_l = *chunk_l $op chunk_r;
+                }
+            }
+        }
+
+        impl<const W: usize, Chunk: ChunkType> core::ops::$assign_trait for $type<W, Chunk> {
+            fn $assign_method(&mut self, rhs: Self) {
+                core::ops::$assign_trait::$assign_method(self, &rhs);
+            }
+        }
+
+        impl<const W: usize, Chunk: ChunkType> core::ops::$trait<&$type<W, Chunk>>
+            for $type<W, Chunk>
+        {
+            type Output = Self;
+            fn $method(mut self, rhs: &Self) -> Self {
+                core::ops::$assign_trait::$assign_method(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<const W: usize, Chunk: ChunkType> core::ops::$trait for $type<W, Chunk> {
+            type Output = Self;
+            fn $method(self, rhs: Self) -> Self {
+                self $op &rhs
+            }
+        }
+
+        impl<const W: usize, Chunk: ChunkType> core::ops::$trait<&$type<W, Chunk>>
+            for &$type<W, Chunk>
+        {
+            type Output = $type<W, Chunk>;
+            fn $method(self, rhs: &$type<W, Chunk>) -> $type<W, Chunk> {
+                $type { chunks: self.chunks } $op rhs
+            }
+        }
+
+        impl<const W: usize, Chunk: ChunkType> core::ops::$trait<$type<W, Chunk>>
+            for &$type<W, Chunk>
+        {
+            type Output = $type<W, Chunk>;
+            fn $method(self, rhs: $type<W, Chunk>) -> $type<W, Chunk> {
+                $type { chunks: self.chunks } $op &rhs
+            }
+        }
+    };
+}
+
+pub(crate) use impl_bitwise_ops;
+
 pub fn count_zeros_chunks<const W: usize, Chunk: ChunkType, Total: TotalBitCounter<Chunk>>(
     chunks: [Chunk; W],
 ) -> Option<Total> {
@@ -125,9 +230,11 @@ pub fn split_shr_chunks<const W: usize, Chunk: ChunkType>(
     chunks: &mut [Chunk; W],
     chunk_offset: usize,
     bit_offset: Chunk::BitCounter,
+    fill: Chunk,
 ) {
+    let (_, infill) = fill.shr_chunk_full(bit_offset, Chunk::ZERO);
     if chunk_offset == 0 {
-        chunks.iter_mut().rfold(Chunk::ZERO, |mut infill, chunk| {
+        chunks.iter_mut().rfold(infill, |mut infill, chunk| {
             (*chunk, infill) = (*chunk).shr_chunk_full(bit_offset, infill);
             infill
         });
@@ -137,11 +244,11 @@ pub fn split_shr_chunks<const W: usize, Chunk: ChunkType>(
             .iter_mut()
             .rev()
             .skip(chunk_offset)
-            .fold(Chunk::ZERO, |mut infill, chunk| {
+            .fold(infill, |mut infill, chunk| {
                 (*chunk, infill) = chunk.shr_chunk_full(bit_offset, infill);
                 infill
             });
-        chunks[W - chunk_offset..].fill(Chunk::ZERO);
+        chunks[W - chunk_offset..].fill(fill);
     }
 }
 
@@ -181,3 +288,462 @@ pub fn shr_chunks_one_over<const W: usize, Chunk: ChunkType>(
     chunks_lo[W - 1] = chunks_hi[W - 1];
     replace(&mut chunks_hi[W - 1], fill)
 }
+
+pub fn shr_trailing_zeros_chunks<const W: usize, Chunk: ChunkType>(chunks: &mut [Chunk; W]) {
+    if let Some(zeros) = trailing_zeros_chunks::<W, Chunk, BitCount<Chunk>>(*chunks) {
+        let (chunk_offset, bit_offset) = zeros.split();
+        split_shr_chunks(chunks, chunk_offset, bit_offset, Chunk::ZERO);
+    }
+}
+
+pub fn div_rem_chunks<const W: usize, Chunk: ChunkType>(
+    mut dividend: [Chunk; W],
+    mut divisor: [Chunk; W],
+) -> Option<([Chunk; W], [Chunk; W])> {
+    let (chunk_offset, bit_offset) =
+        leading_zeros_chunks::<W, Chunk, BitCount<Chunk>>(divisor)?.split();
+    let len = W - chunk_offset;
+    if len == 0 {
+        return None;
+    }
+    let (_, mut dividend_top) = dividend[W - 1].shl_chunk_full(bit_offset, Chunk::ZERO);
+    split_shl_chunks(&mut divisor, 0, bit_offset);
+    split_shl_chunks(&mut dividend, 0, bit_offset);
+    let divisor = &divisor[..len];
+    let divisor_1 = divisor[len - 1];
+    let divisor_2 = if len > 1 {
+        divisor[len - 2]
+    } else {
+        Chunk::ZERO
+    };
+    let mut quotient = [Chunk::ZERO; W];
+    for j in (0..=W - len).rev() {
+        let (window, top) = if j + len == W {
+            (&mut dividend[j..], &mut dividend_top)
+        } else {
+            let (window, rest) = dividend[j..].split_at_mut(len);
+            (window, &mut rest[0])
+        };
+        let dividend_1 = window[len - 1];
+        let dividend_2 = if len > 1 {
+            window[len - 2]
+        } else {
+            Chunk::ZERO
+        };
+        let (mut q, mut r) = if *top == divisor_1 {
+            let (r, overflow) = dividend_1.carrying_add(divisor_1, false);
+            (Chunk::MAX, (!overflow).then_some(r))
+        } else {
+            let (q, r) = dividend_1.carrying_div_rem(divisor_1, *top);
+            (q, Some(r))
+        };
+        while let Some(r_value) = r {
+            let (product_lo, product_hi) = q.carrying_mul(divisor_2, Chunk::ZERO);
+            if (product_hi, product_lo) <= (r_value, dividend_2) {
+                break;
+            }
+            q = q.borrowing_sub(Chunk::ZERO, true).0;
+            let (r_value, overflow) = r_value.carrying_add(divisor_1, false);
+            r = (!overflow).then_some(r_value);
+        }
+        let (carry, mut borrow) = zip(window.iter_mut(), divisor).fold(
+            (Chunk::ZERO, false),
+            |(carry, mut borrow), (chunk, &divisor_chunk)| {
+                let (product, carry) = q.carrying_mul(divisor_chunk, carry);
+                (*chunk, borrow) = chunk.borrowing_sub(product, borrow);
+                (carry, borrow)
+            },
+        );
+        (*top, borrow) = top.borrowing_sub(carry, borrow);
+        if borrow {
+            q = q.borrowing_sub(Chunk::ZERO, true).0;
+            let carry = zip(window.iter_mut(), divisor).fold(
+                false,
+                |mut carry, (chunk, &divisor_chunk)| {
+                    (*chunk, carry) = chunk.carrying_add(divisor_chunk, carry);
+                    carry
+                },
+            );
+            *top = top.carrying_add(Chunk::ZERO, carry).0;
+        }
+        quotient[j] = q;
+    }
+    split_shr_chunks(&mut dividend, 0, bit_offset, Chunk::ZERO);
+    Some((quotient, dividend))
+}
+
+pub fn resize_chunks<const W1: usize, const W2: usize, Chunk: ChunkType>(
+    chunks: [Chunk; W1],
+    fill: Chunk,
+) -> ([Chunk; W2], bool) {
+    let mut result = [fill; W2];
+    let len = W1.min(W2);
+    result[..len].copy_from_slice(&chunks[..len]);
+    let lossy = chunks[len..].iter().any(|&chunk| chunk != fill);
+    (result, lossy)
+}
+
+pub fn concat_chunks<const W1: usize, const W2: usize, const W: usize, Chunk: ChunkType>(
+    hi: [Chunk; W1],
+    lo: [Chunk; W2],
+) -> [Chunk; W] {
+    let mut result = [Chunk::ZERO; W];
+    result[..W2].copy_from_slice(&lo);
+    result[W2..].copy_from_slice(&hi);
+    result
+}
+
+pub fn split_chunks<const W: usize, const W1: usize, const W2: usize, Chunk: ChunkType>(
+    chunks: [Chunk; W],
+) -> ([Chunk; W1], [Chunk; W2]) {
+    let (mut hi, mut lo) = ([Chunk::ZERO; W1], [Chunk::ZERO; W2]);
+    lo.copy_from_slice(&chunks[..W2]);
+    hi.copy_from_slice(&chunks[W2..]);
+    (hi, lo)
+}
+
+pub fn rechunk_chunks<
+    const W1: usize,
+    const W2: usize,
+    Chunk1: ChunkType<BitCounter = u32>,
+    Chunk2: ChunkType<BitCounter = u32>,
+>(
+    chunks: [Chunk1; W1],
+) -> [Chunk2; W2] {
+    let mut result = [Chunk2::ZERO; W2];
+    let (mut index, mut offset) = (0, 0);
+    for chunk in chunks {
+        let mut consumed = 0;
+        while consumed < Chunk1::BITS {
+            let len = (Chunk1::BITS - consumed)
+                .min(Chunk2::BITS - offset)
+                .min(u128::BITS);
+            let (piece, _) = chunk.shr_chunk_full(consumed, Chunk1::ZERO);
+            let piece = piece.wrapping_to_u128() & u128::MAX >> (u128::BITS - len);
+            let (piece, _) = Chunk2::wrapping_from_u128(piece).shl_chunk_full(offset, Chunk2::ZERO);
+            result[index] |= piece;
+            consumed += len;
+            offset += len;
+            if offset == Chunk2::BITS {
+                index += 1;
+                offset = 0;
+            }
+        }
+    }
+    result
+}
+
+pub fn chunks_to_le_bytes<const W: usize, Chunk: ChunkType<BitCounter = u32>, const N: usize>(
+    chunks: [Chunk; W],
+) -> [u8; N] {
+    let mut result = [0; N];
+    let mut bytes = result.iter_mut();
+    for chunk in chunks {
+        for shamt in (0..Chunk::BITS).step_by(8) {
+            let (byte, _) = chunk.shr_chunk_full(shamt, Chunk::ZERO);
+            *bytes.next().unwrap() = byte.wrapping_to_u128() as u8;
+        }
+    }
+    result
+}
+
+pub fn chunks_from_le_bytes<const W: usize, Chunk: ChunkType<BitCounter = u32>>(
+    mut bytes: impl Iterator<Item = u8>,
+) -> [Chunk; W] {
+    let mut result = [Chunk::ZERO; W];
+    for chunk in &mut result {
+        for shamt in (0..Chunk::BITS).step_by(8) {
+            let byte = Chunk::wrapping_from_u128(bytes.next().unwrap() as u128);
+            *chunk |= byte.shl_chunk_full(shamt, Chunk::ZERO).0;
+        }
+    }
+    result
+}
+
+pub fn carrying_mul_chunk_chunks<const W: usize, Chunk: ChunkType>(
+    chunks: &mut [Chunk; W],
+    rhs: Chunk,
+    carry: Chunk,
+) -> Chunk {
+    chunks.iter_mut().fold(carry, |carry, chunk| {
+        let (lo, hi) = chunk.carrying_mul(rhs, carry);
+        *chunk = lo;
+        hi
+    })
+}
+
+pub fn div_rem_chunk_chunks<const W: usize, Chunk: ChunkType>(
+    chunks: &mut [Chunk; W],
+    divisor: Chunk,
+) -> Chunk {
+    let mut remainder = Chunk::ZERO;
+    for chunk in chunks.iter_mut().rev() {
+        (*chunk, remainder) = chunk.carrying_div_rem(divisor, remainder);
+    }
+    remainder
+}
+
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+pub fn pow2_digits_chunks<const W: usize, Chunk: ChunkType>(
+    chunks: [Chunk; W],
+    digit_bits: u32,
+    buf: &mut [u8],
+) -> Option<usize> {
+    let chunk_bits = chunk_bits_u128::<Chunk>() as usize;
+    let chunks = chunks.map(Chunk::wrapping_to_u128);
+    let total_bits = W * chunk_bits;
+    let mut start = buf.len();
+    let mut significant = buf.len().checked_sub(1)?;
+    for position in (0..total_bits).step_by(digit_bits as usize) {
+        let digit = (position..total_bits.min(position + digit_bits as usize))
+            .map(|bit| chunks[bit / chunk_bits] >> (bit % chunk_bits) & 1)
+            .rev()
+            .fold(0, |digit, bit| digit << 1 | bit);
+        start = start.checked_sub(1)?;
+        buf[start] = DIGITS[digit as usize];
+        if digit != 0 {
+            significant = start;
+        }
+    }
+    Some(significant)
+}
+
+// log(2) / log(radix) as a 32-bit fixed point fraction, rounded up, for radix 2..=36
+const LOG_2_RADIX: [u64; 35] = [
+    4294967296, 2709822658, 2147483648, 1849741733, 1661520156, 1529898220, 1431655766, 1354911329,
+    1292913987, 1241523976, 1198050830, 1160664036, 1128071164, 1099331346, 1073741824, 1050766078,
+    1029986702, 1011073585, 993761859, 977836273, 963119892, 949465784, 936750802, 924870867,
+    913737343, 903274220, 893415895, 884105414, 875293063, 866935226, 858993460, 851433730,
+    844225783, 837342624, 830760078,
+];
+
+pub const fn max_digits(bits: usize, radix: u32) -> usize {
+    assert!(
+        2 <= radix && radix <= 36,
+        "radix must lie in the range `[2, 36]`"
+    );
+    if radix.is_power_of_two() {
+        bits.div_ceil(radix.trailing_zeros() as usize)
+    } else {
+        ((bits as u128 * LOG_2_RADIX[radix as usize - 2] as u128) >> 32) as usize + 1
+    }
+}
+
+pub fn radix_digits_chunks<const W: usize, Chunk: ChunkType>(
+    mut chunks: [Chunk; W],
+    radix: u32,
+    buf: &mut [u8],
+) -> Option<usize> {
+    assert!(
+        (2..=36).contains(&radix),
+        "radix must lie in the range `[2, 36]`"
+    );
+    let radix = radix as u128;
+    let max = Chunk::MAX.wrapping_to_u128();
+    let (mut base, mut group) = (radix, 1);
+    while let Some(next) = base.checked_mul(radix).filter(|&next| next <= max) {
+        base = next;
+        group += 1;
+    }
+    let base = Chunk::wrapping_from_u128(base);
+    let mut start = buf.len();
+    loop {
+        let mut remainder = div_rem_chunk_chunks(&mut chunks, base).wrapping_to_u128();
+        let last = chunks.iter().all(|&chunk| chunk == Chunk::ZERO);
+        for _ in 0..group {
+            if last && remainder == 0 && start < buf.len() {
+                break;
+            }
+            start = start.checked_sub(1)?;
+            buf[start] = DIGITS[(remainder % radix) as usize];
+            remainder /= radix;
+        }
+        if last {
+            return Some(start);
+        }
+    }
+}
+
+pub fn chunk_bits_u128<Chunk: ChunkType>() -> u32 {
+    Chunk::MAX.wrapping_to_u128().count_ones()
+}
+
+pub fn chunks_from_u128<const W: usize, Chunk: ChunkType>(mut value: u128) -> ([Chunk; W], bool) {
+    let bits = chunk_bits_u128::<Chunk>();
+    let mut chunks = [Chunk::ZERO; W];
+    for chunk in &mut chunks {
+        *chunk = Chunk::wrapping_from_u128(value);
+        value = value.checked_shr(bits).unwrap_or(0);
+    }
+    (chunks, value != 0)
+}
+
+pub fn chunks_from_i128<const W: usize, Chunk: ChunkType>(mut value: i128) -> ([Chunk; W], bool) {
+    let bits = chunk_bits_u128::<Chunk>();
+    let mut chunks = [Chunk::ZERO; W];
+    for chunk in &mut chunks {
+        *chunk = if value < 0 {
+            !Chunk::wrapping_from_u128(!value as u128)
+        } else {
+            Chunk::wrapping_from_u128(value as u128)
+        };
+        value = value.checked_shr(bits).unwrap_or(value >> (i128::BITS - 1));
+    }
+    let negative = W > 0 && chunks[W - 1].cmp_as_signed(Chunk::ZERO) == Ordering::Less;
+    (chunks, value != if negative { -1 } else { 0 })
+}
+
+pub fn chunks_to_u128<const W: usize, Chunk: ChunkType>(chunks: [Chunk; W]) -> (u128, bool) {
+    let bits = chunk_bits_u128::<Chunk>();
+    let mut shift = 0u32;
+    chunks
+        .into_iter()
+        .fold((0, false), |(value, mut overflow), chunk| {
+            let part = chunk.wrapping_to_u128();
+            let value = match part.checked_shl(shift) {
+                Some(shifted) if shifted >> shift == part => value | shifted,
+                _ => {
+                    overflow |= part != 0;
+                    value
+                }
+            };
+            shift = shift.saturating_add(bits);
+            (value, overflow)
+        })
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "chunks_8",
+        feature = "chunks_16",
+        feature = "chunks_32",
+        feature = "chunks_64",
+        feature = "chunks_128"
+    )
+))]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn next(state: &mut u64) -> u128 {
+        let mut step = || {
+            *state ^= *state << 13;
+            *state ^= *state >> 7;
+            *state ^= *state << 17;
+            *state
+        };
+        let value = (step() as u128) << 64 | step() as u128;
+        match step() % 4 {
+            0 => value >> (step() % 128),
+            1 => u128::MAX - (value & 0xff),
+            _ => value,
+        }
+    }
+
+    fn compare_div_rem<const W: usize, Chunk: ChunkType>(seed: u64) {
+        let mut state = seed;
+        for _ in 0..10_000 {
+            let (n, d) = (next(&mut state), next(&mut state).max(1));
+            let (dividend, _) = chunks_from_u128::<W, Chunk>(n);
+            let (divisor, _) = chunks_from_u128::<W, Chunk>(d);
+            let (quotient, remainder) = div_rem_chunks(dividend, divisor).unwrap();
+            assert_eq!(chunks_to_u128(quotient), (n / d, false));
+            assert_eq!(chunks_to_u128(remainder), (n % d, false));
+        }
+    }
+
+    macro_rules! test_div_rem_chunks {
+        ($feature:literal, $module:ident, $chunk:ty) => {
+            #[cfg(feature = $feature)]
+            mod $module {
+                use super::*;
+
+                const MAX: $chunk = <$chunk>::MAX;
+                const HIGH: $chunk = 1 << (<$chunk>::BITS - 1);
+
+                #[test]
+                fn quotient_estimate_max() {
+                    assert_eq!(
+                        div_rem_chunks([0, MAX - 1, HIGH], [MAX, HIGH, 0]),
+                        Some(([MAX, 0, 0], [MAX, MAX >> 1, 0]))
+                    );
+                }
+
+                #[test]
+                fn add_back() {
+                    assert_eq!(
+                        div_rem_chunks([3, 0, HIGH], [1, 0, HIGH >> 2]),
+                        Some(([3, 0, 0], [0, 0, HIGH >> 2]))
+                    );
+                }
+
+                #[test]
+                fn single_chunk_divisor() {
+                    assert_eq!(
+                        div_rem_chunks([1, 1, 1], [2, 0, 0]),
+                        Some(([HIGH, HIGH, 0], [1, 0, 0]))
+                    );
+                    assert_eq!(
+                        div_rem_chunks([MAX, MAX, MAX], [MAX, 0, 0]),
+                        Some(([1, 1, 1], [0, 0, 0]))
+                    );
+                    assert_eq!(
+                        div_rem_chunks::<3, $chunk>([7, 0, 0], [1, 0, 0]),
+                        Some(([7, 0, 0], [0, 0, 0]))
+                    );
+                }
+
+                #[test]
+                fn divide_by_zero() {
+                    assert_eq!(div_rem_chunks::<3, $chunk>([1, 2, 3], [0, 0, 0]), None);
+                    assert_eq!(div_rem_chunks::<1, $chunk>([0], [0]), None);
+                }
+
+                #[test]
+                fn smaller_dividend() {
+                    assert_eq!(
+                        div_rem_chunks::<3, $chunk>([5, 0, 0], [0, 1, 0]),
+                        Some(([0, 0, 0], [5, 0, 0]))
+                    );
+                }
+            }
+        };
+    }
+
+    test_div_rem_chunks!("chunks_8", chunk_u8, u8);
+    test_div_rem_chunks!("chunks_16", chunk_u16, u16);
+    test_div_rem_chunks!("chunks_32", chunk_u32, u32);
+    test_div_rem_chunks!("chunks_64", chunk_u64, u64);
+    test_div_rem_chunks!("chunks_128", chunk_u128, u128);
+
+    #[cfg(feature = "chunks_8")]
+    #[test]
+    fn div_rem_chunks_u8_matches_u128() {
+        compare_div_rem::<16, u8>(0x9e37_79b9_7f4a_7c15);
+    }
+
+    #[cfg(feature = "chunks_16")]
+    #[test]
+    fn div_rem_chunks_u16_matches_u128() {
+        compare_div_rem::<8, u16>(0x94d0_49bb_1331_11eb);
+    }
+
+    #[cfg(feature = "chunks_32")]
+    #[test]
+    fn div_rem_chunks_u32_matches_u128() {
+        compare_div_rem::<4, u32>(0xbf58_476d_1ce4_e5b9);
+    }
+
+    #[cfg(feature = "chunks_64")]
+    #[test]
+    fn div_rem_chunks_u64_matches_u128() {
+        compare_div_rem::<2, u64>(0xd1b5_4a32_d192_ed03);
+    }
+
+    #[cfg(feature = "chunks_128")]
+    #[test]
+    fn div_rem_chunks_u128_matches_u128() {
+        compare_div_rem::<1, u128>(0x8cb9_2ba7_2f3d_8dd7);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::{
+    common::{chunks_to_u128, ChunkType},
+    error::TryFromIntError,
+    i::I,
+    u::U,
+};
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub trait Primitive: Copy + private::Sealed {
+    fn to_bits(self) -> (u128, bool);
+}
+
+macro_rules! impl_primitive {
+    (unsigned: $($prim:ty),*; signed: $($signed:ty),*) => {
+        $(impl private::Sealed for $prim {}
+        impl Primitive for $prim {
+            fn to_bits(self) -> (u128, bool) {
+                (self as u128, false)
+            }
+        })*
+        $(impl private::Sealed for $signed {}
+        impl Primitive for $signed {
+            fn to_bits(self) -> (u128, bool) {
+                (self as u128, self < 0)
+            }
+        })*
+    };
+}
+
+impl_primitive!(unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_try_from_wide {
+    ($($prim:ty),*) => {$(
+        impl<const W: usize, Chunk: ChunkType> TryFrom<U<W, Chunk>> for $prim {
+            type Error = TryFromIntError;
+            fn try_from(value: U<W, Chunk>) -> Result<Self, TryFromIntError> {
+                match chunks_to_u128(value.chunks) {
+                    (value, false) => Self::try_from(value).map_err(|_| TryFromIntError(())),
+                    (_, true) => Err(TryFromIntError(())),
+                }
+            }
+        }
+
+        impl<const W: usize, Chunk: ChunkType> TryFrom<I<W, Chunk>> for $prim {
+            type Error = TryFromIntError;
+            fn try_from(value: I<W, Chunk>) -> Result<Self, TryFromIntError> {
+                if value < I::ZERO {
+                    match chunks_to_u128((!value).chunks) {
+                        (value, false) if value <= i128::MAX as u128 => {
+                            Self::try_from(!(value as i128)).map_err(|_| TryFromIntError(()))
+                        }
+                        _ => Err(TryFromIntError(())),
+                    }
+                } else {
+                    Self::try_from(value.reinterpret_unsigned())
+                }
+            }
+        }
+    )*};
+}
+
+impl_try_from_wide!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[allow(unused_macros)]
+macro_rules! impl_from_primitive {
+    ($type:ident<$chunk:ty>, from: $($from:ty),*; try_from: $($try_from:ty),*) => {
+        $(impl<const W: usize> From<$from> for $type<W, $chunk> {
+            fn from(value: $from) -> Self {
+                Self::wrapping_from(value)
+            }
+        })*
+        $(impl<const W: usize> TryFrom<$try_from> for $type<W, $chunk> {
+            type Error = TryFromIntError;
+            fn try_from(value: $try_from) -> Result<Self, TryFromIntError> {
+                Self::checked_from(value).ok_or(TryFromIntError(()))
+            }
+        })*
+    };
+}
+
+#[cfg(feature = "chunks_8")]
+impl_from_primitive!(
+    U<u8>,
+    from: u8;
+    try_from: u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+#[cfg(feature = "chunks_8")]
+impl_from_primitive!(
+    I<u8>,
+    from: i8;
+    try_from: u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize
+);
+#[cfg(feature = "chunks_16")]
+impl_from_primitive!(
+    U<u16>,
+    from: u8, u16;
+    try_from: u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+#[cfg(feature = "chunks_16")]
+impl_from_primitive!(
+    I<u16>,
+    from: u8, i8, i16;
+    try_from: u16, u32, u64, u128, usize, i32, i64, i128, isize
+);
+#[cfg(feature = "chunks_32")]
+impl_from_primitive!(
+    U<u32>,
+    from: u8, u16, u32;
+    try_from: u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+#[cfg(feature = "chunks_32")]
+impl_from_primitive!(
+    I<u32>,
+    from: u8, u16, i8, i16, i32;
+    try_from: u32, u64, u128, usize, i64, i128, isize
+);
+#[cfg(feature = "chunks_64")]
+impl_from_primitive!(
+    U<u64>,
+    from: u8, u16, u32, u64;
+    try_from: u128, usize, i8, i16, i32, i64, i128, isize
+);
+#[cfg(feature = "chunks_64")]
+impl_from_primitive!(
+    I<u64>,
+    from: u8, u16, u32, i8, i16, i32, i64;
+    try_from: u64, u128, usize, i128, isize
+);
+#[cfg(feature = "chunks_128")]
+impl_from_primitive!(
+    U<u128>,
+    from: u8, u16, u32, u64, u128;
+    try_from: usize, i8, i16, i32, i64, i128, isize
+);
+#[cfg(feature = "chunks_128")]
+impl_from_primitive!(
+    I<u128>,
+    from: u8, u16, u32, u64, i8, i16, i32, i64, i128;
+    try_from: u128, usize, isize
+);
+
+macro_rules! impl_from_narrower {
+    ($($narrow:literal => $($wide:literal),*);* $(;)?) => {$($(
+        impl<Chunk: ChunkType> From<U<$narrow, Chunk>> for U<$wide, Chunk> {
+            fn from(value: U<$narrow, Chunk>) -> Self {
+                value.zero_extend()
+            }
+        }
+
+        impl<Chunk: ChunkType> From<I<$narrow, Chunk>> for I<$wide, Chunk> {
+            fn from(value: I<$narrow, Chunk>) -> Self {
+                value.sign_extend()
+            }
+        }
+    )*)*};
+}
+
+// `From` cannot be implemented for every `W1 < W2` without generic const
+// expressions, so lossless widening is only provided between the common widths
+// below. For any other pair use `U::zero_extend` / `I::sign_extend`.
+impl_from_narrower!(
+    1 => 2, 3, 4, 6, 8, 12, 16, 24, 32;
+    2 => 3, 4, 6, 8, 12, 16, 24, 32;
+    3 => 4, 6, 8, 12, 16, 24, 32;
+    4 => 6, 8, 12, 16, 24, 32;
+    6 => 8, 12, 16, 24, 32;
+    8 => 12, 16, 24, 32;
+    12 => 16, 24, 32;
+    16 => 24, 32;
+    24 => 32;
+);
+
+#[cfg(all(test, feature = "chunks_64"))]
+mod tests {
+    use crate::{i::I, u::U};
+
+    #[test]
+    fn from_narrower_matches_extend() {
+        let value = U::<2, u64>::from_chunks([1, 2]);
+        assert_eq!(U::<3, u64>::from(value), value.zero_extend::<3>());
+        let value = I::<2, u64>::wrapping_from(-5i8);
+        assert_eq!(
+            I::<3, u64>::from(value.clone()),
+            value.clone().sign_extend::<3>()
+        );
+        assert_eq!(I::<32, u64>::from(value), I::<32, u64>::wrapping_from(-5i8));
+    }
+}
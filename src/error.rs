@@ -0,0 +1,56 @@
+use core::fmt::{self, Display, Formatter};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TryFromIntError(pub(crate) ());
+
+impl Display for TryFromIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range integral type conversion attempted")
+    }
+}
+
+impl core::error::Error for TryFromIntError {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum IntErrorKind {
+    Empty,
+    InvalidDigit { position: usize },
+    PosOverflow,
+    NegOverflow,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseIntError(pub(crate) IntErrorKind);
+
+impl ParseIntError {
+    pub fn kind(&self) -> &IntErrorKind {
+        &self.0
+    }
+}
+
+impl Display for ParseIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            IntErrorKind::Empty => f.write_str("cannot parse integer from empty string"),
+            IntErrorKind::InvalidDigit { position } => {
+                write!(f, "invalid digit found in string at position {position}")
+            }
+            IntErrorKind::PosOverflow => f.write_str("number too large to fit in target type"),
+            IntErrorKind::NegOverflow => f.write_str("number too small to fit in target type"),
+        }
+    }
+}
+
+impl core::error::Error for ParseIntError {}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct BufferTooSmall(pub(crate) ());
+
+impl Display for BufferTooSmall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("buffer too small for formatted integer")
+    }
+}
+
+impl core::error::Error for BufferTooSmall {}
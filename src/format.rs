@@ -0,0 +1,383 @@
+use crate::{
+    common::{pow2_digits_chunks, radix_digits_chunks},
+    i::I,
+    u::U,
+    ChunkType,
+};
+use core::{
+    fmt::{
+        self, Alignment, Binary, Debug, Display, Formatter, LowerExp, LowerHex, Octal, UpperExp,
+        UpperHex, Write,
+    },
+    str,
+};
+
+fn fmt_decimal<const W: usize, Chunk: ChunkType>(
+    chunks: [Chunk; W],
+    nonnegative: bool,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    let mut buf = [[0; 40]; W];
+    let buf = buf.as_flattened_mut();
+    let start = radix_digits_chunks(chunks, 10, buf).expect("decimal digits exceed buffer");
+    f.pad_integral(nonnegative, "", str::from_utf8(&buf[start..]).unwrap())
+}
+
+fn fmt_pow2<const W: usize, Chunk: ChunkType>(
+    chunks: [Chunk; W],
+    digit_bits: u32,
+    prefix: &str,
+    upper: bool,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    let mut buf = [[0; 128]; W];
+    let buf = buf.as_flattened_mut();
+    let start = pow2_digits_chunks(chunks, digit_bits, buf).expect("digits exceed buffer");
+    if upper {
+        buf.make_ascii_uppercase();
+    }
+    f.pad_integral(true, prefix, str::from_utf8(&buf[start..]).unwrap())
+}
+
+fn fmt_exp<const W: usize, Chunk: ChunkType>(
+    chunks: [Chunk; W],
+    nonnegative: bool,
+    upper: bool,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    let mut buf = [[0; 40]; W];
+    let buf = buf.as_flattened_mut();
+    let start = radix_digits_chunks(chunks, 10, buf).expect("decimal digits exceed buffer");
+    let digits = &mut buf[start..];
+    let mut exponent = digits.len() - 1;
+    let len = digits.len()
+        - digits
+            .iter()
+            .rev()
+            .take_while(|&&digit| digit == b'0')
+            .count();
+    let mut mantissa = &mut digits[..len.max(1)];
+    let mut zeros = 0;
+    if let Some(precision) = f.precision() {
+        if precision + 1 < mantissa.len() {
+            let dropped = mantissa[precision + 1];
+            let sticky = mantissa.len() > precision + 2;
+            mantissa = &mut mantissa[..precision + 1];
+            if dropped > b'5' || dropped == b'5' && (sticky || mantissa[precision] % 2 != 0) {
+                match mantissa.iter().rposition(|&digit| digit != b'9') {
+                    Some(index) => {
+                        mantissa[index] += 1;
+                        mantissa[index + 1..].fill(b'0');
+                    }
+                    None => {
+                        mantissa[0] = b'1';
+                        mantissa[1..].fill(b'0');
+                        exponent += 1;
+                    }
+                }
+            }
+        } else {
+            zeros = precision + 1 - mantissa.len();
+        }
+    }
+    let sign = if !nonnegative {
+        "-"
+    } else if f.sign_plus() {
+        "+"
+    } else {
+        ""
+    };
+    let point = mantissa.len() > 1 || zeros > 0;
+    let exponent_len = exponent.checked_ilog10().unwrap_or(0) as usize + 1;
+    let len = sign.len() + mantissa.len() + point as usize + zeros + 1 + exponent_len;
+    let padding = f.width().unwrap_or(0).saturating_sub(len);
+    let (fill, pre, post) = if f.sign_aware_zero_pad() {
+        ('0', 0, 0)
+    } else {
+        match f.align().unwrap_or(Alignment::Right) {
+            Alignment::Left => (f.fill(), 0, padding),
+            Alignment::Right => (f.fill(), padding, 0),
+            Alignment::Center => (f.fill(), padding / 2, padding - padding / 2),
+        }
+    };
+    for _ in 0..pre {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    if f.sign_aware_zero_pad() {
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+    }
+    let (first, rest) = mantissa.split_at(1);
+    f.write_str(str::from_utf8(first).unwrap())?;
+    if point {
+        f.write_char('.')?;
+    }
+    f.write_str(str::from_utf8(rest).unwrap())?;
+    for _ in 0..zeros {
+        f.write_char('0')?;
+    }
+    write!(f, "{}{exponent}", if upper { 'E' } else { 'e' })?;
+    for _ in 0..post {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+macro_rules! impl_pow2_fmt {
+    ($type:ident, $trait:ident, $digit_bits:literal, $prefix:literal, $upper:literal) => {
+        impl<const W: usize, Chunk: ChunkType> $trait for $type<W, Chunk> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                fmt_pow2(self.chunks, $digit_bits, $prefix, $upper, f)
+            }
+        }
+    };
+    ($type:ident) => {
+        impl_pow2_fmt!($type, LowerHex, 4, "0x", false);
+        impl_pow2_fmt!($type, UpperHex, 4, "0x", true);
+        impl_pow2_fmt!($type, Octal, 3, "0o", false);
+        impl_pow2_fmt!($type, Binary, 1, "0b", false);
+    };
+}
+
+impl_pow2_fmt!(U);
+impl_pow2_fmt!(I);
+
+impl<const W: usize, Chunk: ChunkType> LowerExp for U<W, Chunk> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_exp(self.chunks, true, false, f)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> UpperExp for U<W, Chunk> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_exp(self.chunks, true, true, f)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> LowerExp for I<W, Chunk> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let abs = self.clone().unsigned_abs();
+        fmt_exp(abs.chunks, *self >= I::ZERO, false, f)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> UpperExp for I<W, Chunk> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let abs = self.clone().unsigned_abs();
+        fmt_exp(abs.chunks, *self >= I::ZERO, true, f)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Display for U<W, Chunk> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_decimal(self.chunks, true, f)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Display for I<W, Chunk> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let abs = self.clone().unsigned_abs();
+        fmt_decimal(abs.chunks, *self >= I::ZERO, f)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType + Debug> Debug for U<W, Chunk> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("U").field("chunks", &self.chunks).finish()
+        } else {
+            Display::fmt(self, f)
+        }
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType + Debug> Debug for I<W, Chunk> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.debug_struct("I").field("chunks", &self.chunks).finish()
+        } else {
+            Display::fmt(self, f)
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "chunks_8", feature = "chunks_64")))]
+mod tests {
+    use crate::{i::I, u::U};
+
+    macro_rules! test_max_digits {
+        ($feature:literal, $module:ident, $chunk:ty) => {
+            #[cfg(feature = $feature)]
+            mod $module {
+                use super::*;
+
+                #[test]
+                fn extremes_fit_in_max_digits() {
+                    for radix in [2, 8, 10, 16, 36] {
+                        let mut buf = [0; 256];
+                        let buf = &mut buf[..U::<3, $chunk>::max_digits(radix)];
+                        assert!(U::<3, $chunk>::MAX.to_str_radix_into(radix, buf).is_ok());
+                        let mut buf = [0; 256];
+                        let buf = &mut buf[..I::<3, $chunk>::max_digits(radix)];
+                        assert!(I::<3, $chunk>::MAX.to_str_radix_into(radix, buf).is_ok());
+                        assert!(I::<3, $chunk>::MIN.to_str_radix_into(radix, buf).is_ok());
+                    }
+                }
+
+                #[test]
+                fn max_digits_is_tight_for_binary() {
+                    let bits = 3 * <$chunk>::BITS as usize;
+                    let mut buf = [0; 512];
+                    let text = U::<3, $chunk>::MAX.to_str_radix_into(2, &mut buf).unwrap();
+                    assert_eq!(text.len(), U::<3, $chunk>::max_digits(2));
+                    assert_eq!(text.len(), bits);
+                    let text = I::<3, $chunk>::MIN.to_str_radix_into(2, &mut buf).unwrap();
+                    assert_eq!(text.len(), I::<3, $chunk>::max_digits(2));
+                    assert_eq!(text.len(), bits + 1);
+                }
+            }
+        };
+    }
+
+    test_max_digits!("chunks_8", chunk_u8, u8);
+    test_max_digits!("chunks_64", chunk_u64, u64);
+
+    #[cfg(feature = "chunks_64")]
+    mod against_core {
+        extern crate alloc;
+
+        use super::*;
+        use alloc::format;
+
+        const UNSIGNED: [u128; 14] = [
+            0,
+            1,
+            5,
+            15,
+            25,
+            125,
+            9950,
+            12345,
+            99999,
+            10_000_000_000_000_000_000,
+            1 << 100,
+            u64::MAX as u128,
+            u128::MAX - 1,
+            u128::MAX,
+        ];
+
+        macro_rules! assert_fmt {
+            ($value:expr, $expected:expr, $($fmt:literal),*) => {$(
+                assert_eq!(format!($fmt, $value), format!($fmt, $expected), "{}", $fmt);
+            )*};
+        }
+
+        #[test]
+        fn display_matches_core() {
+            for value in UNSIGNED {
+                let wide = U::<2, u64>::wrapping_from(value);
+                assert_fmt!(
+                    wide, value, "{}", "{:?}", "{:45}", "{:<45}", "{:^45}", "{:*^45}", "{:+}",
+                    "{:045}", "{:+045}", "{:<+45?}", "{:3}"
+                );
+                for signed in [value as i128, (value as i128).wrapping_neg()] {
+                    let wide = I::<2, u64>::wrapping_from(signed);
+                    assert_fmt!(
+                        wide, signed, "{}", "{:?}", "{:45}", "{:<45}", "{:^45}", "{:*^45}", "{:+}",
+                        "{:045}", "{:+045}", "{:<+45?}", "{:3}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn alternate_debug_shows_chunks() {
+            let unsigned = U::<2, u64>::from_chunks([1, 2]);
+            assert_eq!(
+                format!("{unsigned:#?}"),
+                "U {\n    chunks: [\n        1,\n        2,\n    ],\n}"
+            );
+            assert_eq!(format!("{unsigned:?}"), "36893488147419103233");
+            let signed = I::<2, u64>::wrapping_from(-1i8);
+            assert_eq!(
+                format!("{signed:#?}"),
+                format!(
+                    "I {{\n    chunks: [\n        {0},\n        {0},\n    ],\n}}",
+                    u64::MAX
+                )
+            );
+            assert_eq!(format!("{signed:?}"), "-1");
+        }
+
+        #[test]
+        fn exp_matches_core() {
+            for value in UNSIGNED {
+                let wide = U::<2, u64>::wrapping_from(value);
+                assert_fmt!(
+                    wide,
+                    value,
+                    "{:e}",
+                    "{:E}",
+                    "{:.0e}",
+                    "{:.1e}",
+                    "{:.3e}",
+                    "{:.40e}",
+                    "{:+025.3e}",
+                    "{:^25.1e}",
+                    "{:<20E}",
+                    "{:*>20.2e}",
+                    "{:030e}"
+                );
+                for signed in [value as i128, (value as i128).wrapping_neg()] {
+                    let wide = I::<2, u64>::wrapping_from(signed);
+                    assert_fmt!(
+                        wide,
+                        signed,
+                        "{:e}",
+                        "{:E}",
+                        "{:.0e}",
+                        "{:.1e}",
+                        "{:.3e}",
+                        "{:.40e}",
+                        "{:+025.3e}",
+                        "{:^25.1e}",
+                        "{:<20E}",
+                        "{:*>20.2e}",
+                        "{:030e}"
+                    );
+                }
+            }
+        }
+
+        #[test]
+        fn exp_rounding_carries() {
+            let wide = |value: u128| U::<2, u64>::wrapping_from(value);
+            assert_eq!(format!("{:.1e}", wide(9950)), "1.0e4");
+            assert_eq!(format!("{:.1e}", wide(9949)), "9.9e3");
+            assert_eq!(format!("{:.0e}", wide(25)), "2e1");
+            assert_eq!(format!("{:.0e}", wide(35)), "4e1");
+            assert_eq!(format!("{:.0e}", wide(251)), "3e2");
+            assert_eq!(format!("{:.2e}", wide(99999)), "1.00e5");
+        }
+
+        #[test]
+        fn pow2_matches_core() {
+            for value in UNSIGNED {
+                let wide = U::<2, u64>::wrapping_from(value);
+                assert_fmt!(
+                    wide, value, "{:x}", "{:X}", "{:o}", "{:b}", "{:#x}", "{:#X}", "{:#o}",
+                    "{:#b}", "{:064x}", "{:#066x}", "{:>40x}", "{:^#40b}"
+                );
+                for signed in [value as i128, (value as i128).wrapping_neg()] {
+                    let wide = I::<2, u64>::wrapping_from(signed);
+                    assert_fmt!(
+                        wide, signed, "{:x}", "{:X}", "{:o}", "{:b}", "{:#x}", "{:#X}", "{:#o}",
+                        "{:#b}", "{:064x}", "{:#066x}", "{:>40x}", "{:^#40b}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,19 +1,28 @@
 use crate::{
     common::{
-        count_ones_chunks, count_zeros_chunks, leading_ones_chunks, leading_zeros_chunks,
+        chunks_from_i128, chunks_from_le_bytes, chunks_from_u128, concat_chunks, count_ones_chunks,
+        count_zeros_chunks, impl_bitwise_ops, leading_ones_chunks, leading_zeros_chunks,
+        max_digits, radix_digits_chunks, rechunk_chunks, resize_chunks, split_chunks,
         split_rotate_left_chunks, split_rotate_right_chunks, split_shl_chunks, split_shr_chunks,
         trailing_ones_chunks, trailing_zeros_chunks, ChunkBitCounter, ChunkType, TotalBitCounter,
     },
+    convert::Primitive,
+    error::{BufferTooSmall, ParseIntError},
+    parse::parse_signed,
     u::U,
 };
 use core::{
     cmp::Ordering,
-    iter::zip,
-    ops::{Add, AddAssign, Shl, ShlAssign, Shr, ShrAssign},
+    fmt::{self, Write},
+    iter::{repeat, zip},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
+    str,
 };
 
-#[derive(Clone, Hash, PartialEq, Eq, Debug)]
-#[cfg_attr(feature = "clone", derive(Clone))]
+#[derive(Clone, Hash, PartialEq, Eq)]
 pub struct I<const W: usize, Chunk: ChunkType> {
     pub(crate) chunks: [Chunk; W],
 }
@@ -36,6 +45,9 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
             chunks
         },
     };
+    pub const NEG_ONE: Self = I {
+        chunks: [Chunk::MAX; W],
+    };
     pub const MAX: Self = I {
         chunks: {
             let mut chunks = [Chunk::MAX; W];
@@ -43,6 +55,25 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
             chunks
         },
     };
+    /// Chunks are little-endian: `chunks[0]` holds the least significant bits.
+    pub const fn from_chunks(chunks: [Chunk; W]) -> Self {
+        I { chunks }
+    }
+    pub const fn as_chunks(&self) -> &[Chunk; W] {
+        &self.chunks
+    }
+    pub const fn as_chunks_mut(&mut self) -> &mut [Chunk; W] {
+        &mut self.chunks
+    }
+    pub const fn into_chunks(self) -> [Chunk; W] {
+        self.chunks
+    }
+    pub const fn chunk(&self, index: usize) -> Chunk {
+        self.chunks[index]
+    }
+    pub const fn set_chunk(&mut self, index: usize, chunk: Chunk) {
+        self.chunks[index] = chunk;
+    }
     pub fn bits<Total: TotalBitCounter<Chunk>>() -> Option<Total> {
         Total::from_chunk_count(W)
     }
@@ -51,11 +82,126 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
             chunks: self.chunks,
         }
     }
+    pub fn overflowing_from<T: Primitive>(value: T) -> (Self, bool) {
+        let (bits, negative) = value.to_bits();
+        if negative {
+            let (chunks, overflow) = chunks_from_i128(bits as i128);
+            (I { chunks }, overflow)
+        } else {
+            let (chunks, overflow) = chunks_from_u128(bits);
+            let result = I { chunks };
+            let overflow = overflow || result < Self::ZERO;
+            (result, overflow)
+        }
+    }
+    pub fn checked_from<T: Primitive>(value: T) -> Option<Self> {
+        let result = Self::overflowing_from(value);
+        (!result.1).then_some(result.0)
+    }
+    pub fn wrapping_from<T: Primitive>(value: T) -> Self {
+        Self::overflowing_from(value).0
+    }
+    pub fn saturating_from<T: Primitive>(value: T) -> Self {
+        match Self::overflowing_from(value) {
+            (result, false) => result,
+            (_, true) if value.to_bits().1 => Self::MIN,
+            (_, true) => Self::MAX,
+        }
+    }
+    pub fn resize<const W2: usize>(self) -> I<W2, Chunk> {
+        let fill = if self < Self::ZERO {
+            Chunk::MAX
+        } else {
+            Chunk::ZERO
+        };
+        let (chunks, _) = resize_chunks(self.chunks, fill);
+        I { chunks }
+    }
+    pub fn sign_extend<const W2: usize>(self) -> I<W2, Chunk> {
+        const { assert!(W2 >= W, "sign_extend cannot narrow") };
+        self.resize()
+    }
+    pub fn truncate<const W2: usize>(self) -> I<W2, Chunk> {
+        const { assert!(W2 <= W, "truncate cannot widen") };
+        self.resize()
+    }
+    pub fn checked_narrow<const W2: usize>(self) -> Option<I<W2, Chunk>> {
+        let result = I {
+            chunks: self.chunks,
+        }
+        .resize::<W2>();
+        let fill = if result < I::ZERO {
+            Chunk::MAX
+        } else {
+            Chunk::ZERO
+        };
+        let (_, lossy) = resize_chunks::<W, W2, Chunk>(self.chunks, fill);
+        (!lossy).then_some(result)
+    }
+    pub fn concat<const W1: usize, const W2: usize>(hi: I<W1, Chunk>, lo: U<W2, Chunk>) -> Self {
+        const { assert!(W1 + W2 == W, "concat requires W1 + W2 == W") };
+        I {
+            chunks: concat_chunks(hi.chunks, lo.chunks),
+        }
+    }
+    pub fn from_hi_lo<const W2: usize>(hi: I<W2, Chunk>, lo: U<W2, Chunk>) -> Self {
+        Self::concat(hi, lo)
+    }
+    pub fn split_hi_lo<const W2: usize>(self) -> (I<W2, Chunk>, U<W2, Chunk>) {
+        const { assert!(W2 * 2 == W, "split_hi_lo requires W2 * 2 == W") };
+        let (hi, lo) = split_chunks(self.chunks);
+        (I { chunks: hi }, U { chunks: lo })
+    }
+    pub fn rechunk<const W2: usize, Chunk2: ChunkType<BitCounter = u32>>(self) -> I<W2, Chunk2>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        const {
+            assert!(
+                W * Chunk::BITS as usize == W2 * Chunk2::BITS as usize,
+                "rechunk requires equal bit widths"
+            )
+        };
+        I {
+            chunks: rechunk_chunks(self.chunks),
+        }
+    }
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        parse_signed(src, Some(radix), false)
+    }
+    pub fn from_str_radix_lenient(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        parse_signed(src, Some(radix), true)
+    }
+    pub const fn max_digits(radix: u32) -> usize
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        max_digits(W * Chunk::BITS as usize, radix) + 1
+    }
+    pub fn to_str_radix_into<'a>(
+        &self,
+        radix: u32,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, BufferTooSmall> {
+        let abs = self.clone().unsigned_abs();
+        let mut start = radix_digits_chunks(abs.chunks, radix, buf).ok_or(BufferTooSmall(()))?;
+        if *self < Self::ZERO {
+            start = start.checked_sub(1).ok_or(BufferTooSmall(()))?;
+            buf[start] = b'-';
+        }
+        Ok(str::from_utf8(&buf[start..]).unwrap())
+    }
+    pub fn write_radix(&self, radix: u32, mut writer: impl Write) -> fmt::Result {
+        if *self < Self::ZERO {
+            writer.write_char('-')?;
+        }
+        self.clone().unsigned_abs().write_radix(radix, writer)
+    }
     pub fn count_ones<Total: TotalBitCounter<Chunk>>(self) -> Option<Total> {
-        count_zeros_chunks(self.chunks)
+        count_ones_chunks(self.chunks)
     }
     pub fn count_zeros<Total: TotalBitCounter<Chunk>>(self) -> Option<Total> {
-        count_ones_chunks(self.chunks)
+        count_zeros_chunks(self.chunks)
     }
     pub fn leading_zeros<Total: TotalBitCounter<Chunk>>(self) -> Option<Total> {
         leading_zeros_chunks(self.chunks)
@@ -97,11 +243,87 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
     }
     pub fn checked_add(self, rhs: Self) -> Option<Self> {
         let result = self.overflowing_add(rhs);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
     }
     pub fn wrapping_add(self, rhs: Self) -> Self {
         self.carrying_add(rhs, false).0
     }
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        let negative = rhs < Self::ZERO;
+        match self.overflowing_add(rhs) {
+            (result, false) => result,
+            (_, true) if negative => Self::MIN,
+            (_, true) => Self::MAX,
+        }
+    }
+    pub fn borrowing_sub_in_place(&mut self, rhs: Self, mut borrow: bool) -> bool {
+        if W == 0 {
+            return borrow;
+        }
+        let mut iter = zip(&mut self.chunks, rhs.chunks);
+        let (last_chunk_l, last_chunk_r) = iter.next_back().unwrap();
+        borrow = iter.fold(borrow, |mut borrow, (chunk_l, chunk_r)| {
+            (*chunk_l, borrow) = chunk_l.borrowing_sub(chunk_r, borrow);
+            borrow
+        });
+        (*last_chunk_l, borrow) = last_chunk_l.borrowing_sub_as_signed(last_chunk_r, borrow);
+        borrow
+    }
+    pub fn overflowing_sub_in_place(&mut self, rhs: Self) -> bool {
+        self.borrowing_sub_in_place(rhs, false)
+    }
+    pub fn wrapping_sub_in_place(&mut self, rhs: Self) {
+        self.overflowing_sub_in_place(rhs);
+    }
+    pub fn borrowing_sub(mut self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let borrow = self.borrowing_sub_in_place(rhs, borrow);
+        (self, borrow)
+    }
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        self.borrowing_sub(rhs, false)
+    }
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let result = self.overflowing_sub(rhs);
+        (!result.1).then_some(result.0)
+    }
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.borrowing_sub(rhs, false).0
+    }
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        let negative = rhs < Self::ZERO;
+        match self.overflowing_sub(rhs) {
+            (result, false) => result,
+            (_, true) if negative => Self::MAX,
+            (_, true) => Self::MIN,
+        }
+    }
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        Self::ZERO.overflowing_sub(self)
+    }
+    pub fn checked_neg(self) -> Option<Self> {
+        let result = self.overflowing_neg();
+        (!result.1).then_some(result.0)
+    }
+    pub fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+    pub fn saturating_neg(self) -> Self {
+        self.checked_neg().unwrap_or(Self::MAX)
+    }
+    pub fn saturating_abs(self) -> Self {
+        if self < Self::ZERO {
+            self.saturating_neg()
+        } else {
+            self
+        }
+    }
+    pub fn unsigned_abs(self) -> U<W, Chunk> {
+        if self < Self::ZERO {
+            self.wrapping_neg().reinterpret_unsigned()
+        } else {
+            self.reinterpret_unsigned()
+        }
+    }
     pub fn split_overflowing_shl_in_place(
         &mut self,
         chunk_offset: usize,
@@ -121,7 +343,12 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
         let overflow = chunk_offset >= W;
         let chunk_offset = chunk_offset % W;
         assert!(bit_offset.is_valid());
-        split_shr_chunks(&mut self.chunks, chunk_offset, bit_offset);
+        let fill = if *self < Self::ZERO {
+            Chunk::MAX
+        } else {
+            Chunk::ZERO
+        };
+        split_shr_chunks(&mut self.chunks, chunk_offset, bit_offset, fill);
         overflow
     }
     pub fn overflowing_shl_in_place<Total: TotalBitCounter<Chunk>>(&mut self, rhs: Total) -> bool {
@@ -147,7 +374,7 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
         self.split_overflowing_shr_in_place(chunk_offset, bit_offset);
     }
     pub fn wrapping_shl_in_place<Total: TotalBitCounter<Chunk>>(&mut self, rhs: Total) {
-        self.overflowing_shr_in_place(rhs);
+        self.overflowing_shl_in_place(rhs);
     }
     pub fn wrapping_shr_in_place<Total: TotalBitCounter<Chunk>>(&mut self, rhs: Total) {
         self.overflowing_shr_in_place(rhs);
@@ -194,7 +421,7 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
         bit_offset: Chunk::BitCounter,
     ) -> Option<Self> {
         let result = self.split_overflowing_shl(chunk_offset, bit_offset);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
     }
     pub fn split_checked_shr(
         self,
@@ -202,15 +429,18 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
         bit_offset: Chunk::BitCounter,
     ) -> Option<Self> {
         let result = self.split_overflowing_shr(chunk_offset, bit_offset);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
     }
     pub fn checked_shl<Total: TotalBitCounter<Chunk>>(self, rhs: Total) -> Option<Self> {
         let result = self.overflowing_shl(rhs);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
     }
     pub fn checked_shr<Total: TotalBitCounter<Chunk>>(self, rhs: Total) -> Option<Self> {
         let result = self.overflowing_shr(rhs);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
+    }
+    pub fn logical_shr<Total: TotalBitCounter<Chunk>>(self, rhs: Total) -> Self {
+        (self.reinterpret_unsigned() >> rhs).reinterpret_signed()
     }
     pub fn split_rotate_left_in_place(
         &mut self,
@@ -218,7 +448,7 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
         bit_offset: Chunk::BitCounter,
     ) {
         assert!(bit_offset.is_valid());
-        split_rotate_left_chunks(&mut self.chunks, chunk_offset, bit_offset);
+        split_rotate_left_chunks(&mut self.chunks, chunk_offset % W, bit_offset);
     }
     pub fn split_rotate_right_in_place(
         &mut self,
@@ -226,7 +456,7 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
         bit_offset: Chunk::BitCounter,
     ) {
         assert!(bit_offset.is_valid());
-        split_rotate_right_chunks(&mut self.chunks, chunk_offset, bit_offset);
+        split_rotate_right_chunks(&mut self.chunks, chunk_offset % W, bit_offset);
     }
     pub fn rotate_left_in_place<Total: TotalBitCounter<Chunk>>(&mut self, n: Total) {
         let (chunk_offset, bit_offset) = n.split();
@@ -259,6 +489,12 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
     pub fn swap_chunks_in_place(&mut self) {
         self.chunks.reverse();
     }
+    pub fn swap_bytes_in_place(&mut self) {
+        self.chunks.reverse();
+        for chunk in &mut self.chunks {
+            *chunk = chunk.swap_bytes();
+        }
+    }
     pub fn swap_bits_in_place(&mut self) {
         self.chunks.reverse();
         for chunk in &mut self.chunks {
@@ -269,16 +505,426 @@ impl<const W: usize, Chunk: ChunkType> I<W, Chunk> {
         self.swap_chunks_in_place();
         self
     }
+    pub fn swap_bytes(mut self) -> Self {
+        self.swap_bytes_in_place();
+        self
+    }
     pub fn swap_bits(mut self) -> Self {
         self.swap_bits_in_place();
         self
     }
+    pub fn to_be(self) -> Self {
+        if cfg!(target_endian = "big") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+    pub fn to_le(self) -> Self {
+        if cfg!(target_endian = "little") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+    pub fn from_be(value: Self) -> Self {
+        value.to_be()
+    }
+    pub fn from_le(value: Self) -> Self {
+        value.to_le()
+    }
+    pub fn to_le_bytes<const N: usize>(self) -> [u8; N]
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        self.reinterpret_unsigned().to_le_bytes()
+    }
+    pub fn to_be_bytes<const N: usize>(self) -> [u8; N]
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        self.reinterpret_unsigned().to_be_bytes()
+    }
+    pub fn to_ne_bytes<const N: usize>(self) -> [u8; N]
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        self.reinterpret_unsigned().to_ne_bytes()
+    }
+    pub fn from_le_bytes<const N: usize>(bytes: [u8; N]) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        U::from_le_bytes(bytes).reinterpret_signed()
+    }
+    pub fn from_be_bytes<const N: usize>(bytes: [u8; N]) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        U::from_be_bytes(bytes).reinterpret_signed()
+    }
+    pub fn from_ne_bytes<const N: usize>(bytes: [u8; N]) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        U::from_ne_bytes(bytes).reinterpret_signed()
+    }
+    pub fn from_le_slice(bytes: &[u8]) -> Option<Self>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        const {
+            assert!(
+                Chunk::BITS % 8 == 0,
+                "chunk width must be a whole number of bytes"
+            )
+        };
+        let fill = if bytes.last().is_some_and(|byte| byte & 0x80 != 0) {
+            u8::MAX
+        } else {
+            0
+        };
+        (bytes.len() <= W * (Chunk::BITS / 8) as usize).then(|| I {
+            chunks: chunks_from_le_bytes(bytes.iter().copied().chain(repeat(fill))),
+        })
+    }
+    pub fn from_be_slice(bytes: &[u8]) -> Option<Self>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        const {
+            assert!(
+                Chunk::BITS % 8 == 0,
+                "chunk width must be a whole number of bytes"
+            )
+        };
+        let fill = if bytes.first().is_some_and(|byte| byte & 0x80 != 0) {
+            u8::MAX
+        } else {
+            0
+        };
+        (bytes.len() <= W * (Chunk::BITS / 8) as usize).then(|| I {
+            chunks: chunks_from_le_bytes(bytes.iter().rev().copied().chain(repeat(fill))),
+        })
+    }
     pub fn overflowing_add_unsigned(self, rhs: U<W, Chunk>) -> (Self, bool) {
         let rhs = rhs.reinterpret_signed();
         let negative = rhs < Self::ZERO;
         let (result, overflow) = self.overflowing_add(rhs);
         (result, overflow ^ negative)
     }
+    pub fn saturating_add_unsigned(self, rhs: U<W, Chunk>) -> Self {
+        match self.overflowing_add_unsigned(rhs) {
+            (result, false) => result,
+            (_, true) => Self::MAX,
+        }
+    }
+    pub fn carrying_mul(self, rhs: Self, carry: Self) -> (U<W, Chunk>, Self) {
+        let negative_l = self < Self::ZERO;
+        let negative_r = rhs < Self::ZERO;
+        let negative_carry = carry < Self::ZERO;
+        let lhs = self.reinterpret_unsigned();
+        let rhs = rhs.reinterpret_unsigned();
+        let (lo, mut hi) = lhs.carrying_mul(rhs, carry.reinterpret_unsigned());
+        if negative_l {
+            hi.overflowing_sub_in_place(rhs);
+        }
+        if negative_r {
+            hi.overflowing_sub_in_place(lhs);
+        }
+        if negative_carry {
+            hi.overflowing_sub_in_place(U::ONE);
+        }
+        (lo, hi.reinterpret_signed())
+    }
+    pub fn widening_mul(self, rhs: Self) -> (U<W, Chunk>, Self) {
+        self.carrying_mul(rhs, Self::ZERO)
+    }
+    pub fn overflowing_mul_in_place(&mut self, rhs: Self) -> bool {
+        let (lo, hi) = I {
+            chunks: self.chunks,
+        }
+        .widening_mul(rhs);
+        *self = lo.reinterpret_signed();
+        let sign_extension = if *self < Self::ZERO {
+            U::MAX.reinterpret_signed()
+        } else {
+            Self::ZERO
+        };
+        hi != sign_extension
+    }
+    pub fn wrapping_mul_in_place(&mut self, rhs: Self) {
+        self.overflowing_mul_in_place(rhs);
+    }
+    pub fn overflowing_mul(mut self, rhs: Self) -> (Self, bool) {
+        let overflow = self.overflowing_mul_in_place(rhs);
+        (self, overflow)
+    }
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let result = self.overflowing_mul(rhs);
+        (!result.1).then_some(result.0)
+    }
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        let negative = (self < Self::ZERO) != (rhs < Self::ZERO);
+        match self.overflowing_mul(rhs) {
+            (result, false) => result,
+            (_, true) if negative => Self::MIN,
+            (_, true) => Self::MAX,
+        }
+    }
+    pub fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        let (mut base, mut result, mut overflow) = (self, Self::ONE, false);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                overflow |= result.overflowing_mul_in_place(base.clone());
+            }
+            exp >>= 1;
+            if exp > 0 {
+                overflow |= base.overflowing_mul_in_place(base.clone());
+            }
+        }
+        (result, overflow)
+    }
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let (mut base, mut result) = (self, Self::ONE);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base.clone())?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone().checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+    pub fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+    pub fn saturating_pow(self, exp: u32) -> Self {
+        let negative = self < Self::ZERO && exp % 2 == 1;
+        let magnitude = self.unsigned_abs().saturating_pow(exp).reinterpret_signed();
+        if negative {
+            let result = magnitude.wrapping_neg();
+            if result > Self::ZERO {
+                Self::MIN
+            } else {
+                result
+            }
+        } else if magnitude < Self::ZERO {
+            Self::MAX
+        } else {
+            magnitude
+        }
+    }
+    #[cfg(overflow_checks)]
+    pub fn pow(self, exp: u32) -> Self {
+        self.checked_pow(exp)
+            .expect("attempt to multiply with overflow")
+    }
+    #[cfg(not(overflow_checks))]
+    pub fn pow(self, exp: u32) -> Self {
+        self.wrapping_pow(exp)
+    }
+    pub fn checked_isqrt(self) -> Option<Self>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        (self >= Self::ZERO).then(|| self.reinterpret_unsigned().isqrt().reinterpret_signed())
+    }
+    pub fn isqrt(self) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        self.checked_isqrt()
+            .expect("argument of integer square root cannot be negative")
+    }
+    pub fn checked_ilog2(self) -> Option<u32>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        (self > Self::ZERO)
+            .then(|| self.reinterpret_unsigned().checked_ilog2())
+            .flatten()
+    }
+    pub fn checked_ilog(self, base: Self) -> Option<u32> {
+        if self <= Self::ZERO || base <= Self::ONE {
+            return None;
+        }
+        self.reinterpret_unsigned()
+            .checked_ilog(base.reinterpret_unsigned())
+    }
+    pub fn checked_ilog10(self) -> Option<u32> {
+        (self > Self::ZERO)
+            .then(|| self.reinterpret_unsigned().checked_ilog10())
+            .flatten()
+    }
+    pub fn ilog2(self) -> u32
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+    pub fn ilog(self, base: Self) -> u32 {
+        assert!(
+            base > Self::ONE,
+            "base of integer logarithm must be at least 2"
+        );
+        self.checked_ilog(base)
+            .expect("argument of integer logarithm must be positive")
+    }
+    pub fn ilog10(self) -> u32 {
+        self.checked_ilog10()
+            .expect("argument of integer logarithm must be positive")
+    }
+    pub fn gcd(self, rhs: Self) -> U<W, Chunk> {
+        self.unsigned_abs().gcd(rhs.unsigned_abs())
+    }
+    pub fn checked_lcm(self, rhs: Self) -> Option<U<W, Chunk>> {
+        self.unsigned_abs().checked_lcm(rhs.unsigned_abs())
+    }
+    pub fn lcm(self, rhs: Self) -> U<W, Chunk> {
+        self.unsigned_abs().lcm(rhs.unsigned_abs())
+    }
+    pub fn extended_gcd(self, rhs: Self) -> (U<W, Chunk>, Self, Self) {
+        let (lhs_negative, rhs_negative) = (self < Self::ZERO, rhs < Self::ZERO);
+        let (gcd, x, y) = self.unsigned_abs().extended_gcd(rhs.unsigned_abs());
+        let x = if lhs_negative { x.wrapping_neg() } else { x };
+        let y = if rhs_negative { y.wrapping_neg() } else { y };
+        (gcd, x, y)
+    }
+    pub fn div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        let negative_l = self < Self::ZERO;
+        let negative_r = rhs < Self::ZERO;
+        let (quotient, remainder) = self.unsigned_abs().div_rem(rhs.unsigned_abs())?;
+        let quotient = quotient.reinterpret_signed();
+        let remainder = remainder.reinterpret_signed();
+        Some((
+            if negative_l != negative_r {
+                quotient.wrapping_neg()
+            } else {
+                quotient
+            },
+            if negative_l {
+                remainder.wrapping_neg()
+            } else {
+                remainder
+            },
+        ))
+    }
+    pub fn div_rem_euclid(self, rhs: Self) -> Option<(Self, Self)> {
+        let negative_r = rhs < Self::ZERO;
+        let rhs_abs = I { chunks: rhs.chunks }.unsigned_abs();
+        let (quotient, remainder) = self.div_rem(rhs)?;
+        if remainder < Self::ZERO {
+            let quotient = if negative_r {
+                quotient.wrapping_add(Self::ONE)
+            } else {
+                quotient.wrapping_sub(Self::ONE)
+            };
+            let remainder = remainder.reinterpret_unsigned().wrapping_add(rhs_abs);
+            Some((quotient, remainder.reinterpret_signed()))
+        } else {
+            Some((quotient, remainder))
+        }
+    }
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        let overflow = self == Self::MIN && rhs == Self::NEG_ONE;
+        let (quotient, _) = self.div_rem(rhs).expect("attempt to divide by zero");
+        (quotient, overflow)
+    }
+    pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        let overflow = self == Self::MIN && rhs == Self::NEG_ONE;
+        let (_, remainder) = self
+            .div_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero");
+        (remainder, overflow)
+    }
+    pub fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) {
+        let overflow = self == Self::MIN && rhs == Self::NEG_ONE;
+        let (quotient, _) = self.div_rem_euclid(rhs).expect("attempt to divide by zero");
+        (quotient, overflow)
+    }
+    pub fn overflowing_rem_euclid(self, rhs: Self) -> (Self, bool) {
+        let overflow = self == Self::MIN && rhs == Self::NEG_ONE;
+        let (_, remainder) = self
+            .div_rem_euclid(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero");
+        (remainder, overflow)
+    }
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            return None;
+        }
+        self.div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            return None;
+        }
+        self.div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+    pub fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            return None;
+        }
+        self.div_rem_euclid(rhs).map(|(quotient, _)| quotient)
+    }
+    pub fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+        if self == Self::MIN && rhs == Self::NEG_ONE {
+            return None;
+        }
+        self.div_rem_euclid(rhs).map(|(_, remainder)| remainder)
+    }
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+    pub fn wrapping_rem(self, rhs: Self) -> Self {
+        self.overflowing_rem(rhs).0
+    }
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        let (quotient, overflow) = self.overflowing_div_euclid(rhs);
+        assert!(!overflow, "attempt to divide with overflow");
+        quotient
+    }
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let (remainder, overflow) = self.overflowing_rem_euclid(rhs);
+        assert!(
+            !overflow,
+            "attempt to calculate the remainder with overflow"
+        );
+        remainder
+    }
+    pub fn div_floor(self, rhs: Self) -> Self {
+        assert!(
+            !(self == Self::MIN && rhs == Self::NEG_ONE),
+            "attempt to divide with overflow"
+        );
+        let negative_r = rhs < Self::ZERO;
+        let (quotient, remainder) = self.div_rem(rhs).expect("attempt to divide by zero");
+        if remainder != Self::ZERO && (remainder < Self::ZERO) != negative_r {
+            quotient.wrapping_sub(Self::ONE)
+        } else {
+            quotient
+        }
+    }
+    pub fn div_ceil(self, rhs: Self) -> Self {
+        assert!(
+            !(self == Self::MIN && rhs == Self::NEG_ONE),
+            "attempt to divide with overflow"
+        );
+        let negative_r = rhs < Self::ZERO;
+        let (quotient, remainder) = self.div_rem(rhs).expect("attempt to divide by zero");
+        if remainder != Self::ZERO && (remainder < Self::ZERO) == negative_r {
+            quotient.wrapping_add(Self::ONE)
+        } else {
+            quotient
+        }
+    }
 }
 
 impl<const W: usize, Chunk: ChunkType> PartialOrd for I<W, Chunk> {
@@ -289,8 +935,8 @@ impl<const W: usize, Chunk: ChunkType> PartialOrd for I<W, Chunk> {
 
 impl<const W: usize, Chunk: ChunkType> Ord for I<W, Chunk> {
     fn cmp(&self, other: &Self) -> Ordering {
-        let mut iter_l = self.chunks.into_iter();
-        let mut iter_r = other.chunks.into_iter();
+        let mut iter_l = self.chunks.into_iter().rev();
+        let mut iter_r = other.chunks.into_iter().rev();
         let first_chunk_l = iter_l.next().unwrap();
         let first_chunk_r = iter_r.next().unwrap();
         match first_chunk_l.cmp_as_signed(first_chunk_r) {
@@ -317,7 +963,7 @@ impl<const W: usize, Chunk: ChunkType> AddAssign for I<W, Chunk> {
     #[cfg(overflow_checks)]
     fn add_assign(&mut self, rhs: Self) {
         assert!(
-            self.overflowing_add_in_place(rhs),
+            !self.overflowing_add_in_place(rhs),
             "attempt to add with overflow"
         );
     }
@@ -327,6 +973,111 @@ impl<const W: usize, Chunk: ChunkType> AddAssign for I<W, Chunk> {
     }
 }
 
+impl<const W: usize, Chunk: ChunkType> Sub for I<W, Chunk> {
+    type Output = Self;
+    #[cfg(overflow_checks)]
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+    #[cfg(not(overflow_checks))]
+    fn sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> SubAssign for I<W, Chunk> {
+    #[cfg(overflow_checks)]
+    fn sub_assign(&mut self, rhs: Self) {
+        assert!(
+            !self.overflowing_sub_in_place(rhs),
+            "attempt to subtract with overflow"
+        );
+    }
+    #[cfg(not(overflow_checks))]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.wrapping_sub_in_place(rhs);
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Mul for I<W, Chunk> {
+    type Output = Self;
+    #[cfg(overflow_checks)]
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+    #[cfg(not(overflow_checks))]
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> MulAssign for I<W, Chunk> {
+    #[cfg(overflow_checks)]
+    fn mul_assign(&mut self, rhs: Self) {
+        assert!(
+            !self.overflowing_mul_in_place(rhs),
+            "attempt to multiply with overflow"
+        );
+    }
+    #[cfg(not(overflow_checks))]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.wrapping_mul_in_place(rhs);
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Div for I<W, Chunk> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let (quotient, overflow) = self.overflowing_div(rhs);
+        assert!(!overflow, "attempt to divide with overflow");
+        quotient
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> DivAssign for I<W, Chunk> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = I {
+            chunks: self.chunks,
+        } / rhs;
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Rem for I<W, Chunk> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        let (remainder, overflow) = self.overflowing_rem(rhs);
+        assert!(
+            !overflow,
+            "attempt to calculate the remainder with overflow"
+        );
+        remainder
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> RemAssign for I<W, Chunk> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = I {
+            chunks: self.chunks,
+        } % rhs;
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Neg for I<W, Chunk> {
+    type Output = Self;
+    #[cfg(overflow_checks)]
+    fn neg(self) -> Self {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+    #[cfg(not(overflow_checks))]
+    fn neg(self) -> Self {
+        self.wrapping_neg()
+    }
+}
+
+impl_bitwise_ops!(I);
+
 impl<const W: usize, Chunk: ChunkType, Total: TotalBitCounter<Chunk>> Shl<Total> for I<W, Chunk> {
     type Output = Self;
     #[cfg(overflow_checks)]
@@ -359,13 +1110,13 @@ impl<const W: usize, Chunk: ChunkType, Total: TotalBitCounter<Chunk>> ShlAssign<
     #[cfg(overflow_checks)]
     fn shl_assign(&mut self, rhs: Total) {
         assert!(
-            self.overflowing_shl_in_place(rhs),
+            !self.overflowing_shl_in_place(rhs),
             "attempt to shift left with overflow"
         );
     }
     #[cfg(not(overflow_checks))]
     fn shl_assign(&mut self, rhs: Total) {
-        self.wrapping_shr_in_place(rhs);
+        self.wrapping_shl_in_place(rhs);
     }
 }
 
@@ -375,7 +1126,7 @@ impl<const W: usize, Chunk: ChunkType, Total: TotalBitCounter<Chunk>> ShrAssign<
     #[cfg(overflow_checks)]
     fn shr_assign(&mut self, rhs: Total) {
         assert!(
-            self.overflowing_shr_in_place(rhs),
+            !self.overflowing_shr_in_place(rhs),
             "attempt to shift right with overflow"
         );
     }
@@ -384,3 +1135,325 @@ impl<const W: usize, Chunk: ChunkType, Total: TotalBitCounter<Chunk>> ShrAssign<
         self.wrapping_shr_in_place(rhs);
     }
 }
+
+#[cfg(all(test, feature = "chunks_64"))]
+mod tests {
+    use super::I;
+    use crate::u::U;
+
+    type I2 = I<2, u64>;
+
+    fn i(value: i128) -> I2 {
+        I2::wrapping_from(value)
+    }
+
+    const DIVIDENDS: [i128; 12] = [
+        0,
+        1,
+        -1,
+        6,
+        -6,
+        7,
+        -7,
+        0x1234_5678_9abc_def0_1234_5678,
+        -0x1234_5678_9abc_def0_1234_5678,
+        i128::MAX,
+        i128::MIN + 1,
+        i128::MIN,
+    ];
+
+    const DIVISORS: [i128; 10] = [1, -1, 2, -2, 3, -3, 7 << 70, -7 << 70, i128::MAX, i128::MIN];
+
+    fn div_floor(lhs: i128, rhs: i128) -> i128 {
+        let (quotient, remainder) = (lhs / rhs, lhs % rhs);
+        if remainder != 0 && (remainder < 0) != (rhs < 0) {
+            quotient - 1
+        } else {
+            quotient
+        }
+    }
+
+    fn div_ceil(lhs: i128, rhs: i128) -> i128 {
+        let (quotient, remainder) = (lhs / rhs, lhs % rhs);
+        if remainder != 0 && (remainder < 0) == (rhs < 0) {
+            quotient + 1
+        } else {
+            quotient
+        }
+    }
+
+    #[test]
+    fn saturating_pow() {
+        assert_eq!(i(-2).saturating_pow(127), I2::MIN);
+        assert_eq!(i(-2).saturating_pow(128), I2::MAX);
+        assert_eq!(i(2).saturating_pow(127), I2::MAX);
+        assert_eq!(i(-3).saturating_pow(5), i(-243));
+        assert_eq!(i(-3).saturating_pow(0), i(1));
+    }
+
+    #[test]
+    fn ilog() {
+        assert_eq!(i(-10).checked_ilog10(), None);
+        assert_eq!(i(0).checked_ilog10(), None);
+        assert_eq!(i(999).checked_ilog10(), Some(2));
+        assert_eq!(i(1000).checked_ilog10(), Some(3));
+        assert_eq!(I2::MAX.checked_ilog10(), Some(38));
+        assert_eq!(i(-8).checked_ilog2(), None);
+        assert_eq!(i(8).checked_ilog(i(2)), Some(3));
+    }
+
+    #[test]
+    fn gcd() {
+        let u = |value: u128| U::<2, u64>::wrapping_from(value);
+        assert_eq!(i(-48).gcd(i(180)), u(12));
+        assert_eq!(i(48).gcd(i(-180)), u(12));
+        assert_eq!(i(-48).gcd(i(0)), u(48));
+        assert_eq!(I2::MIN.gcd(I2::MIN), u(1 << 127));
+        assert_eq!(i(-4).lcm(i(-6)), u(12));
+        assert_eq!(i(-4).checked_lcm(i(6)), Some(u(12)));
+    }
+
+    #[test]
+    fn extended_gcd() {
+        for (lhs, rhs) in [
+            (240, 46),
+            (-240, 46),
+            (240, -46),
+            (-240, -46),
+            (0, -7),
+            (-7, 0),
+        ] {
+            let (gcd, x, y) = i(lhs).extended_gcd(i(rhs));
+            assert_eq!(gcd, i(lhs).gcd(i(rhs)));
+            assert_eq!(i(lhs) * x + i(rhs) * y, gcd.reinterpret_signed());
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        for value in [
+            0,
+            1,
+            -1,
+            0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10,
+            -0x1234_5678,
+            i128::MIN,
+        ] {
+            assert_eq!(i(value).swap_bytes(), i(value.swap_bytes()));
+            assert_eq!(i(value).to_be(), i(value.to_be()));
+            assert_eq!(i(value).to_le(), i(value.to_le()));
+            assert_eq!(I2::from_be(i(value)), i(i128::from_be(value)));
+            assert_eq!(i(value).to_le_bytes(), value.to_le_bytes());
+            assert_eq!(i(value).to_be_bytes(), value.to_be_bytes());
+            assert_eq!(i(value).to_ne_bytes(), value.to_ne_bytes());
+            assert_eq!(I2::from_le_bytes(value.to_le_bytes()), i(value));
+            assert_eq!(I2::from_be_bytes(value.to_be_bytes()), i(value));
+            assert_eq!(I2::from_ne_bytes(value.to_ne_bytes()), i(value));
+            assert_eq!(I2::from_le_slice(&value.to_le_bytes()), Some(i(value)));
+            assert_eq!(I2::from_be_slice(&value.to_be_bytes()), Some(i(value)));
+        }
+    }
+
+    #[test]
+    fn byte_slices_sign_extend() {
+        assert_eq!(I2::from_le_slice(&[]), Some(i(0)));
+        assert_eq!(I2::from_be_slice(&[]), Some(i(0)));
+        for value in [0i16, 1, -1, 0x7fff, -0x8000, 0x1234, -0x1234, 0x80, -0x80] {
+            assert_eq!(
+                I2::from_le_slice(&value.to_le_bytes()),
+                Some(i(value as i128))
+            );
+            assert_eq!(
+                I2::from_be_slice(&value.to_be_bytes()),
+                Some(i(value as i128))
+            );
+        }
+        assert_eq!(I2::from_le_slice(&[0x80]), Some(i(-128)));
+        assert_eq!(I2::from_be_slice(&[0xff, 0x7f]), Some(i(-129)));
+        assert_eq!(I2::from_le_slice(&[0x7f]), Some(i(127)));
+        assert_eq!(I2::from_le_slice(&[0; 17]), None);
+        assert_eq!(I2::from_be_slice(&[0xff; 17]), None);
+    }
+
+    #[test]
+    fn widening_mul_matches_i128() {
+        let values = [
+            0,
+            1,
+            -1,
+            2,
+            -2,
+            0x1234_5678_9abc_def0,
+            -0x1234_5678_9abc_def0,
+            i64::MAX,
+            i64::MIN,
+        ];
+        let carries = [0, 1, -1, i64::MAX, i64::MIN, -0x1234_5678];
+        for lhs in values {
+            for rhs in values {
+                for carry in carries {
+                    let product = lhs as i128 * rhs as i128 + carry as i128;
+                    let expected = (
+                        U::<1, u64>::wrapping_from(product as u64),
+                        I::<1, u64>::wrapping_from((product >> 64) as i64),
+                    );
+                    let (a, b, c) = (
+                        I::<1, u64>::wrapping_from(lhs),
+                        I::<1, u64>::wrapping_from(rhs),
+                        I::<1, u64>::wrapping_from(carry),
+                    );
+                    assert_eq!(a.clone().carrying_mul(b.clone(), c), expected);
+                    if carry == 0 {
+                        assert_eq!(a.clone().widening_mul(b.clone()), expected);
+                    }
+                    let expected = |value: Option<i64>| value.map(I::<1, u64>::wrapping_from);
+                    assert_eq!(
+                        a.clone().checked_mul(b.clone()),
+                        expected(lhs.checked_mul(rhs))
+                    );
+                    let (wrapped, overflow) = lhs.overflowing_mul(rhs);
+                    assert_eq!(
+                        a.clone().overflowing_mul(b.clone()),
+                        (I::wrapping_from(wrapped), overflow)
+                    );
+                    assert_eq!(
+                        a.saturating_mul(b),
+                        I::wrapping_from(lhs.saturating_mul(rhs))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn widening_mul_extremes() {
+        let i1 = |value: i64| I::<1, u64>::wrapping_from(value);
+        let u1 = |value: u64| U::<1, u64>::wrapping_from(value);
+        assert_eq!(I::<1, u64>::MIN.widening_mul(I::MIN), (u1(0), i1(1 << 62)));
+        assert_eq!(I::<1, u64>::MIN.widening_mul(i1(-1)), (u1(1 << 63), i1(0)));
+        assert_eq!(i1(-1).widening_mul(i1(-1)), (u1(1), i1(0)));
+        assert_eq!(i1(0).carrying_mul(i1(0), i1(-1)), (u1(u64::MAX), i1(-1)));
+        assert_eq!(
+            i1(3).carrying_mul(i1(-5), i1(-1)),
+            (u1(-16i64 as u64), i1(-1))
+        );
+    }
+
+    #[test]
+    fn division_matches_i128() {
+        for lhs in DIVIDENDS {
+            for rhs in DIVISORS {
+                let (a, b) = (i(lhs), i(rhs));
+                let expected = |value: Option<i128>| value.map(i);
+                assert_eq!(
+                    a.clone().checked_div(b.clone()),
+                    expected(lhs.checked_div(rhs))
+                );
+                assert_eq!(
+                    a.clone().checked_rem(b.clone()),
+                    expected(lhs.checked_rem(rhs))
+                );
+                assert_eq!(
+                    a.clone().checked_div_euclid(b.clone()),
+                    expected(lhs.checked_div_euclid(rhs))
+                );
+                assert_eq!(
+                    a.clone().checked_rem_euclid(b.clone()),
+                    expected(lhs.checked_rem_euclid(rhs))
+                );
+                let (quotient, overflow) = lhs.overflowing_div(rhs);
+                assert_eq!(
+                    a.clone().overflowing_div(b.clone()),
+                    (i(quotient), overflow)
+                );
+                let (remainder, overflow) = lhs.overflowing_rem(rhs);
+                assert_eq!(
+                    a.clone().overflowing_rem(b.clone()),
+                    (i(remainder), overflow)
+                );
+                let (quotient, overflow) = lhs.overflowing_div_euclid(rhs);
+                assert_eq!(
+                    a.clone().overflowing_div_euclid(b.clone()),
+                    (i(quotient), overflow)
+                );
+                let (remainder, overflow) = lhs.overflowing_rem_euclid(rhs);
+                assert_eq!(
+                    a.clone().overflowing_rem_euclid(b.clone()),
+                    (i(remainder), overflow)
+                );
+                assert_eq!(
+                    a.clone().div_rem(b.clone()),
+                    Some((i(lhs.wrapping_div(rhs)), i(lhs.wrapping_rem(rhs))))
+                );
+                assert_eq!(
+                    a.clone().div_rem_euclid(b.clone()),
+                    Some((
+                        i(lhs.wrapping_div_euclid(rhs)),
+                        i(lhs.wrapping_rem_euclid(rhs))
+                    ))
+                );
+                if lhs != i128::MIN || rhs != -1 {
+                    assert_eq!(a.clone() / b.clone(), i(lhs / rhs));
+                    assert_eq!(a.clone() % b.clone(), i(lhs % rhs));
+                    assert_eq!(a.clone().div_euclid(b.clone()), i(lhs.div_euclid(rhs)));
+                    assert_eq!(a.clone().rem_euclid(b.clone()), i(lhs.rem_euclid(rhs)));
+                    assert_eq!(a.clone().div_floor(b.clone()), i(div_floor(lhs, rhs)));
+                    assert_eq!(a.div_ceil(b), i(div_ceil(lhs, rhs)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rounding_direction() {
+        for (lhs, rhs, floor, ceil) in [
+            (7, 2, 3, 4),
+            (-7, 2, -4, -3),
+            (7, -2, -4, -3),
+            (-7, -2, 3, 4),
+        ] {
+            assert_eq!(i(lhs).div_floor(i(rhs)), i(floor));
+            assert_eq!(i(lhs).div_ceil(i(rhs)), i(ceil));
+            assert_eq!(
+                i(lhs).div_euclid(i(rhs)) * i(rhs) + i(lhs).rem_euclid(i(rhs)),
+                i(lhs)
+            );
+            assert!(i(lhs).rem_euclid(i(rhs)) >= i(0));
+        }
+        assert_eq!(i(-6).div_floor(i(3)), i(-2));
+        assert_eq!(i(-6).div_ceil(i(3)), i(-2));
+    }
+
+    #[test]
+    fn min_by_negative_one() {
+        assert_eq!(I2::MIN.checked_div(i(-1)), None);
+        assert_eq!(I2::MIN.checked_rem(i(-1)), None);
+        assert_eq!(I2::MIN.checked_div_euclid(i(-1)), None);
+        assert_eq!(I2::MIN.checked_rem_euclid(i(-1)), None);
+        assert_eq!(I2::MIN.overflowing_div(i(-1)), (I2::MIN, true));
+        assert_eq!(I2::MIN.overflowing_rem(i(-1)), (i(0), true));
+        assert_eq!(I2::MIN.wrapping_div(i(-1)), I2::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide with overflow")]
+    fn div_floor_min_by_negative_one() {
+        I2::MIN.div_floor(i(-1));
+    }
+
+    #[test]
+    fn zero_divisor() {
+        assert_eq!(i(5).div_rem(i(0)), None);
+        assert_eq!(i(5).div_rem_euclid(i(0)), None);
+        assert_eq!(i(5).checked_div(i(0)), None);
+        assert_eq!(i(5).checked_rem(i(0)), None);
+        assert_eq!(i(5).checked_div_euclid(i(0)), None);
+        assert_eq!(i(5).checked_rem_euclid(i(0)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_ceil_by_zero() {
+        i(5).div_ceil(i(0));
+    }
+}
@@ -2,10 +2,20 @@
 #![feature(cfg_overflow_checks)]
 #![forbid(unsafe_code)]
 
+mod bit_count;
+mod chunks;
 mod common;
+mod convert;
+mod error;
+mod format;
 mod i;
+mod literal;
+mod parse;
 mod u;
 
+pub use bit_count::BitCount;
 pub use common::{ChunkBitCounter, ChunkType, TotalBitCounter};
+pub use convert::Primitive;
+pub use error::{BufferTooSmall, IntErrorKind, ParseIntError, TryFromIntError};
 pub use i::I;
 pub use u::U;
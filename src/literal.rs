@@ -0,0 +1,168 @@
+#[allow(unused_macros)]
+macro_rules! impl_const_parse {
+    ($chunk:ty, |$x:ident, $r:ident, $c:ident| $mul_add:block) => {
+        impl<const W: usize> crate::U<W, $chunk> {
+            const fn parse_literal(src: &str, signed: bool) -> (bool, [$chunk; W]) {
+                let bytes = src.as_bytes();
+                let negative = !bytes.is_empty() && bytes[0] == b'-';
+                let mut index = negative as usize;
+                assert!(signed || !negative, "negative literal for unsigned type");
+                let mut radix = 10;
+                if bytes.len() >= index + 2 && bytes[index] == b'0' {
+                    radix = match bytes[index + 1] {
+                        b'x' => 16,
+                        b'o' => 8,
+                        b'b' => 2,
+                        _ => 10,
+                    };
+                    if radix != 10 {
+                        index += 2;
+                    }
+                }
+                let mut chunks = [0; W];
+                let mut any_digits = false;
+                while index < bytes.len() {
+                    let byte = bytes[index];
+                    index += 1;
+                    if byte == b'_' {
+                        continue;
+                    }
+                    let digit = match byte {
+                        b'0'..=b'9' => byte - b'0',
+                        b'a'..=b'f' => byte - b'a' + 10,
+                        b'A'..=b'F' => byte - b'A' + 10,
+                        _ => panic!("invalid digit in literal"),
+                    };
+                    assert!(digit < radix, "invalid digit in literal");
+                    let mut carry = digit as $chunk;
+                    let mut i = 0;
+                    while i < W {
+                        let ($x, $r, $c) = (chunks[i], radix as $chunk, carry);
+                        (chunks[i], carry) = $mul_add;
+                        i += 1;
+                    }
+                    assert!(carry == 0, "literal out of range");
+                    any_digits = true;
+                }
+                assert!(any_digits, "literal has no digits");
+                (negative, chunks)
+            }
+
+            pub const fn from_str_const(src: &str) -> Self {
+                let (_, chunks) = Self::parse_literal(src, false);
+                crate::U { chunks }
+            }
+        }
+
+        impl<const W: usize> crate::I<W, $chunk> {
+            pub const fn from_str_const(src: &str) -> Self {
+                let (negative, mut chunks) = crate::U::<W, $chunk>::parse_literal(src, true);
+                if negative {
+                    let mut carry = true;
+                    let mut i = 0;
+                    while i < W {
+                        (chunks[i], carry) = (!chunks[i]).overflowing_add(carry as $chunk);
+                        i += 1;
+                    }
+                }
+                let mut zero = true;
+                let mut i = 0;
+                while i < W {
+                    zero &= chunks[i] == 0;
+                    i += 1;
+                }
+                let sign = chunks[W - 1] >> (<$chunk>::BITS - 1) == 1;
+                assert!(sign == negative || zero, "literal out of range");
+                crate::I { chunks }
+            }
+        }
+    };
+    ($chunk:ty, $wide:ty) => {
+        impl_const_parse!($chunk, |x, r, c| {
+            let wide = x as $wide * r as $wide + c as $wide;
+            (wide as $chunk, (wide >> <$chunk>::BITS) as $chunk)
+        });
+    };
+}
+
+#[cfg(feature = "chunks_8")]
+impl_const_parse!(u8, u16);
+#[cfg(feature = "chunks_16")]
+impl_const_parse!(u16, u32);
+#[cfg(feature = "chunks_32")]
+impl_const_parse!(u32, u64);
+#[cfg(feature = "chunks_64")]
+impl_const_parse!(u64, u128);
+#[cfg(feature = "chunks_128")]
+impl_const_parse!(u128, |x, r, c| {
+    const HALF: u32 = u64::BITS;
+    let lo = (x as u64 as u128) * r + c;
+    let hi = (x >> HALF) * r + (lo >> HALF);
+    (hi << HALF | lo as u64 as u128, hi >> HALF)
+});
+
+/// Builds a `U` from an integer literal at compile time.
+///
+/// ```
+/// const KEY: tymp::U<2, u64> = tymp::u!(2, u64; 0xffff_ffff_ffff_ffff_0000_0000_0000_0001);
+/// assert_eq!(KEY.chunk(1), u64::MAX);
+/// ```
+///
+/// Literals that do not fit fail to compile:
+///
+/// ```compile_fail
+/// const TOO_BIG: tymp::U<1, u64> = tymp::u!(1, u64; 18446744073709551616);
+/// ```
+///
+/// ```compile_fail
+/// const NEGATIVE: tymp::U<1, u64> = tymp::u!(1, u64; -1);
+/// ```
+#[macro_export]
+macro_rules! u {
+    ($w:expr, $chunk:ty; $literal:literal) => {
+        const { $crate::U::<$w, $chunk>::from_str_const(stringify!($literal)) }
+    };
+}
+
+/// Builds an `I` from an integer literal at compile time.
+///
+/// ```compile_fail
+/// const TOO_SMALL: tymp::I<1, u64> = tymp::i!(1, u64; -9223372036854775809);
+/// ```
+#[macro_export]
+macro_rules! i {
+    ($w:expr, $chunk:ty; $literal:literal) => {
+        const { $crate::I::<$w, $chunk>::from_str_const(stringify!($literal)) }
+    };
+}
+
+#[cfg(all(test, feature = "chunks_64"))]
+mod tests {
+    use crate::{i::I, u::U};
+
+    const DECIMAL: U<2, u64> = u!(2, u64; 340282366920938463463374607431768211455);
+    const HEX: U<2, u64> = u!(2, u64; 0x1234_5678_9abc_def0_0fed_cba9_8765_4321);
+    const OCTAL: U<1, u64> = u!(1, u64; 0o777);
+    const BINARY: U<1, u64> = u!(1, u64; 0b1010_1010);
+    const SEPARATED: U<1, u64> = u!(1, u64; 1_000_000);
+    const MIN: I<2, u64> = i!(2, u64; -170141183460469231731687303715884105728);
+    const MAX: I<2, u64> = i!(2, u64; 170141183460469231731687303715884105727);
+    const NEGATIVE_HEX: I<1, u64> = i!(1, u64; -0x8000_0000_0000_0000);
+
+    #[test]
+    fn literals() {
+        assert_eq!(DECIMAL, U::MAX);
+        assert_eq!(
+            HEX,
+            U::wrapping_from(0x1234_5678_9abc_def0_0fed_cba9_8765_4321u128)
+        );
+        assert_eq!(OCTAL, U::wrapping_from(0o777u16));
+        assert_eq!(BINARY, U::wrapping_from(0b1010_1010u8));
+        assert_eq!(SEPARATED, U::wrapping_from(1_000_000u32));
+        assert_eq!(MIN, I::MIN);
+        assert_eq!(MAX, I::MAX);
+        assert_eq!(NEGATIVE_HEX, I::MIN);
+        assert_eq!(i!(1, u64; 0), I::ZERO);
+        assert_eq!(i!(1, u64; -1), I::NEG_ONE);
+    }
+}
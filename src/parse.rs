@@ -0,0 +1,208 @@
+use crate::{
+    common::carrying_mul_chunk_chunks,
+    error::{IntErrorKind, ParseIntError},
+    i::I,
+    u::U,
+    ChunkType,
+};
+use core::str::FromStr;
+
+fn parse_magnitude<const W: usize, Chunk: ChunkType>(
+    src: &str,
+    radix: Option<u32>,
+    lenient: bool,
+    signed: bool,
+) -> Result<(bool, [Chunk; W]), ParseIntError> {
+    if let Some(radix) = radix {
+        assert!(
+            (2..=36).contains(&radix),
+            "from_str_radix: radix must lie in the range `[2, 36]` - found {radix}"
+        );
+    }
+    if src.is_empty() {
+        return Err(ParseIntError(IntErrorKind::Empty));
+    }
+    let (negative, digits) = match src.as_bytes()[0] {
+        b'+' => (false, &src[1..]),
+        b'-' if signed => (true, &src[1..]),
+        _ => (false, src),
+    };
+    let (radix, digits) = match radix {
+        Some(radix) => (radix, digits),
+        None => [("0x", 16), ("0o", 8), ("0b", 2)]
+            .into_iter()
+            .find_map(|(prefix, radix)| Some((radix, digits.strip_prefix(prefix)?)))
+            .unwrap_or((10, digits)),
+    };
+    if digits.is_empty() {
+        return Err(ParseIntError(IntErrorKind::InvalidDigit {
+            position: src.len() - 1,
+        }));
+    }
+    let offset = src.len() - digits.len();
+    let invalid = |index| {
+        ParseIntError(IntErrorKind::InvalidDigit {
+            position: offset + index,
+        })
+    };
+    let mut separated = false;
+    let mut result = [Chunk::ZERO; W];
+    for (index, char) in digits.char_indices() {
+        if lenient && char == '_' && index > 0 && !separated {
+            separated = true;
+            continue;
+        }
+        separated = false;
+        let digit = char.to_digit(radix).ok_or_else(|| invalid(index))?;
+        let carry = carrying_mul_chunk_chunks(
+            &mut result,
+            Chunk::wrapping_from_u128(radix as u128),
+            Chunk::wrapping_from_u128(digit as u128),
+        );
+        if carry != Chunk::ZERO {
+            return Err(ParseIntError(if negative {
+                IntErrorKind::NegOverflow
+            } else {
+                IntErrorKind::PosOverflow
+            }));
+        }
+    }
+    if separated {
+        return Err(invalid(digits.len() - 1));
+    }
+    Ok((negative, result))
+}
+
+pub(crate) fn parse_unsigned<const W: usize, Chunk: ChunkType>(
+    src: &str,
+    radix: Option<u32>,
+    lenient: bool,
+) -> Result<U<W, Chunk>, ParseIntError> {
+    let (_, chunks) = parse_magnitude(src, radix, lenient, false)?;
+    Ok(U { chunks })
+}
+
+pub(crate) fn parse_signed<const W: usize, Chunk: ChunkType>(
+    src: &str,
+    radix: Option<u32>,
+    lenient: bool,
+) -> Result<I<W, Chunk>, ParseIntError> {
+    let (negative, chunks) = parse_magnitude(src, radix, lenient, true)?;
+    let magnitude = I { chunks };
+    if negative {
+        let result = I { chunks }.wrapping_neg();
+        if result > I::ZERO || result == I::ZERO && magnitude != I::ZERO {
+            return Err(ParseIntError(IntErrorKind::NegOverflow));
+        }
+        Ok(result)
+    } else if magnitude < I::ZERO {
+        Err(ParseIntError(IntErrorKind::PosOverflow))
+    } else {
+        Ok(magnitude)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> FromStr for U<W, Chunk> {
+    type Err = ParseIntError;
+    fn from_str(src: &str) -> Result<Self, ParseIntError> {
+        parse_unsigned(src, None, false)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> FromStr for I<W, Chunk> {
+    type Err = ParseIntError;
+    fn from_str(src: &str) -> Result<Self, ParseIntError> {
+        parse_signed(src, None, false)
+    }
+}
+
+#[cfg(all(test, feature = "chunks_64"))]
+mod tests {
+    use crate::{
+        error::{IntErrorKind, ParseIntError},
+        i::I,
+        u::U,
+    };
+
+    fn unsigned(src: &str) -> Result<U<2, u64>, IntErrorKind> {
+        src.parse().map_err(|error: ParseIntError| *error.kind())
+    }
+
+    fn signed(src: &str) -> Result<I<2, u64>, IntErrorKind> {
+        src.parse().map_err(|error: ParseIntError| *error.kind())
+    }
+
+    fn lenient(src: &str) -> Result<U<2, u64>, IntErrorKind> {
+        U::from_str_radix_lenient(src, 10).map_err(|error| *error.kind())
+    }
+
+    fn invalid(position: usize) -> IntErrorKind {
+        IntErrorKind::InvalidDigit { position }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(unsigned(""), Err(IntErrorKind::Empty));
+        assert_eq!(signed(""), Err(IntErrorKind::Empty));
+    }
+
+    #[test]
+    fn dangling_sign_or_prefix() {
+        assert_eq!(unsigned("+"), Err(invalid(0)));
+        assert_eq!(unsigned("-"), Err(invalid(0)));
+        assert_eq!(signed("+"), Err(invalid(0)));
+        assert_eq!(signed("-"), Err(invalid(0)));
+        assert_eq!(unsigned("0x"), Err(invalid(1)));
+        assert_eq!(signed("-0b"), Err(invalid(2)));
+    }
+
+    #[test]
+    fn invalid_digit_position() {
+        assert_eq!(unsigned("12a"), Err(invalid(2)));
+        assert_eq!(unsigned("-1"), Err(invalid(0)));
+        assert_eq!(signed("--1"), Err(invalid(1)));
+        assert_eq!(unsigned("0x1g"), Err(invalid(3)));
+        assert_eq!(unsigned("0o18"), Err(invalid(3)));
+        assert_eq!(unsigned("1_000"), Err(invalid(1)));
+    }
+
+    #[test]
+    fn overflow() {
+        let max = "340282366920938463463374607431768211455";
+        assert_eq!(unsigned(max), Ok(U::from_chunks([u64::MAX; 2])));
+        assert_eq!(
+            unsigned("340282366920938463463374607431768211456"),
+            Err(IntErrorKind::PosOverflow)
+        );
+        assert_eq!(
+            signed("170141183460469231731687303715884105727"),
+            Ok(I::MAX)
+        );
+        assert_eq!(
+            signed("-170141183460469231731687303715884105728"),
+            Ok(I::MIN)
+        );
+        assert_eq!(
+            signed("170141183460469231731687303715884105728"),
+            Err(IntErrorKind::PosOverflow)
+        );
+        assert_eq!(
+            signed("-170141183460469231731687303715884105729"),
+            Err(IntErrorKind::NegOverflow)
+        );
+        assert_eq!(
+            signed("-1000000000000000000000000000000000000000"),
+            Err(IntErrorKind::NegOverflow)
+        );
+    }
+
+    #[test]
+    fn lenient_separators() {
+        assert_eq!(lenient("1_000_000"), Ok(U::wrapping_from(1_000_000u32)));
+        assert_eq!(lenient("_1"), Err(invalid(0)));
+        assert_eq!(lenient("1_"), Err(invalid(1)));
+        assert_eq!(lenient("1__0"), Err(invalid(2)));
+        assert_eq!(lenient("1_000__"), Err(invalid(6)));
+        assert_eq!(lenient("1_000_"), Err(invalid(5)));
+    }
+}
@@ -1,20 +1,32 @@
 use crate::{
+    bit_count::BitCount,
     common::{
-        count_ones_chunks, count_zeros_chunks, leading_ones_chunks, leading_zeros_chunks,
-        shr_chunks_one_over, split_rotate_left_chunks, split_rotate_right_chunks, split_shl_chunks,
-        split_shr_chunks, trailing_ones_chunks, trailing_zeros_chunks, ChunkBitCounter, ChunkType,
-        TotalBitCounter,
+        chunk_bits_u128, chunks_from_i128, chunks_from_le_bytes, chunks_from_u128,
+        chunks_to_le_bytes, concat_chunks, count_ones_chunks, count_zeros_chunks, div_rem_chunks,
+        impl_bitwise_ops, leading_ones_chunks, leading_zeros_chunks, max_digits,
+        radix_digits_chunks, rechunk_chunks, resize_chunks, shr_chunks_one_over,
+        shr_trailing_zeros_chunks, split_chunks, split_rotate_left_chunks,
+        split_rotate_right_chunks, split_shl_chunks, split_shr_chunks, trailing_ones_chunks,
+        trailing_zeros_chunks, ChunkBitCounter, ChunkType, TotalBitCounter,
     },
+    convert::Primitive,
+    error::{BufferTooSmall, ParseIntError},
     i::I,
+    parse::parse_unsigned,
 };
 use core::{
     cmp::Ordering,
-    iter::zip,
-    ops::{Add, AddAssign, Shl, ShlAssign, Shr, ShrAssign},
+    fmt::{self, Write},
+    iter::{repeat, zip},
+    mem::{replace, swap},
+    ops::{
+        Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Shl, ShlAssign, Shr,
+        ShrAssign, Sub, SubAssign,
+    },
+    str,
 };
 
-#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
-#[cfg_attr(feature = "clone", derive(Clone))]
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
 pub struct U<const W: usize, Chunk: ChunkType> {
     pub(crate) chunks: [Chunk; W],
 }
@@ -34,6 +46,25 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
     pub const MAX: Self = U {
         chunks: [Chunk::MAX; W],
     };
+    /// Chunks are little-endian: `chunks[0]` holds the least significant bits.
+    pub const fn from_chunks(chunks: [Chunk; W]) -> Self {
+        U { chunks }
+    }
+    pub const fn as_chunks(&self) -> &[Chunk; W] {
+        &self.chunks
+    }
+    pub const fn as_chunks_mut(&mut self) -> &mut [Chunk; W] {
+        &mut self.chunks
+    }
+    pub const fn into_chunks(self) -> [Chunk; W] {
+        self.chunks
+    }
+    pub const fn chunk(&self, index: usize) -> Chunk {
+        self.chunks[index]
+    }
+    pub const fn set_chunk(&mut self, index: usize, chunk: Chunk) {
+        self.chunks[index] = chunk;
+    }
     pub fn bits<Total: TotalBitCounter<Chunk>>() -> Option<Total> {
         Total::from_chunk_count(W)
     }
@@ -42,11 +73,106 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
             chunks: self.chunks,
         }
     }
+    pub fn overflowing_from<T: Primitive>(value: T) -> (Self, bool) {
+        let (bits, negative) = value.to_bits();
+        if negative {
+            let (chunks, _) = chunks_from_i128(bits as i128);
+            (U { chunks }, true)
+        } else {
+            let (chunks, overflow) = chunks_from_u128(bits);
+            (U { chunks }, overflow)
+        }
+    }
+    pub fn checked_from<T: Primitive>(value: T) -> Option<Self> {
+        let result = Self::overflowing_from(value);
+        (!result.1).then_some(result.0)
+    }
+    pub fn wrapping_from<T: Primitive>(value: T) -> Self {
+        Self::overflowing_from(value).0
+    }
+    pub fn saturating_from<T: Primitive>(value: T) -> Self {
+        match Self::overflowing_from(value) {
+            (result, false) => result,
+            (_, true) if value.to_bits().1 => Self::MIN,
+            (_, true) => Self::MAX,
+        }
+    }
+    pub fn resize<const W2: usize>(self) -> U<W2, Chunk> {
+        let (chunks, _) = resize_chunks(self.chunks, Chunk::ZERO);
+        U { chunks }
+    }
+    pub fn zero_extend<const W2: usize>(self) -> U<W2, Chunk> {
+        const { assert!(W2 >= W, "zero_extend cannot narrow") };
+        self.resize()
+    }
+    pub fn truncate<const W2: usize>(self) -> U<W2, Chunk> {
+        const { assert!(W2 <= W, "truncate cannot widen") };
+        self.resize()
+    }
+    pub fn checked_narrow<const W2: usize>(self) -> Option<U<W2, Chunk>> {
+        let (chunks, lossy) = resize_chunks(self.chunks, Chunk::ZERO);
+        (!lossy).then_some(U { chunks })
+    }
+    pub fn concat<const W1: usize, const W2: usize>(hi: U<W1, Chunk>, lo: U<W2, Chunk>) -> Self {
+        const { assert!(W1 + W2 == W, "concat requires W1 + W2 == W") };
+        U {
+            chunks: concat_chunks(hi.chunks, lo.chunks),
+        }
+    }
+    pub fn from_hi_lo<const W2: usize>(hi: U<W2, Chunk>, lo: U<W2, Chunk>) -> Self {
+        Self::concat(hi, lo)
+    }
+    pub fn split_hi_lo<const W2: usize>(self) -> (U<W2, Chunk>, U<W2, Chunk>) {
+        const { assert!(W2 * 2 == W, "split_hi_lo requires W2 * 2 == W") };
+        let (hi, lo) = split_chunks(self.chunks);
+        (U { chunks: hi }, U { chunks: lo })
+    }
+    pub fn rechunk<const W2: usize, Chunk2: ChunkType<BitCounter = u32>>(self) -> U<W2, Chunk2>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        const {
+            assert!(
+                W * Chunk::BITS as usize == W2 * Chunk2::BITS as usize,
+                "rechunk requires equal bit widths"
+            )
+        };
+        U {
+            chunks: rechunk_chunks(self.chunks),
+        }
+    }
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        parse_unsigned(src, Some(radix), false)
+    }
+    pub fn from_str_radix_lenient(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        parse_unsigned(src, Some(radix), true)
+    }
+    pub const fn max_digits(radix: u32) -> usize
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        max_digits(W * Chunk::BITS as usize, radix)
+    }
+    pub fn to_str_radix_into<'a>(
+        &self,
+        radix: u32,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, BufferTooSmall> {
+        let start = radix_digits_chunks(self.chunks, radix, buf).ok_or(BufferTooSmall(()))?;
+        Ok(str::from_utf8(&buf[start..]).unwrap())
+    }
+    pub fn write_radix(&self, radix: u32, mut writer: impl Write) -> fmt::Result {
+        let mut buf = [[0; 128]; W];
+        writer.write_str(
+            self.to_str_radix_into(radix, buf.as_flattened_mut())
+                .expect("digits exceed buffer"),
+        )
+    }
     pub fn count_ones<Total: TotalBitCounter<Chunk>>(self) -> Option<Total> {
-        count_zeros_chunks(self.chunks)
+        count_ones_chunks(self.chunks)
     }
     pub fn count_zeros<Total: TotalBitCounter<Chunk>>(self) -> Option<Total> {
-        count_ones_chunks(self.chunks)
+        count_zeros_chunks(self.chunks)
     }
     pub fn leading_zeros<Total: TotalBitCounter<Chunk>>(self) -> Option<Total> {
         leading_zeros_chunks(self.chunks)
@@ -86,6 +212,38 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
     pub fn wrapping_add(self, rhs: Self) -> Self {
         self.carrying_add(rhs, false).0
     }
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).unwrap_or(Self::MAX)
+    }
+    pub fn borrowing_sub_in_place(&mut self, rhs: Self, borrow: bool) -> bool {
+        if W == 0 {
+            return borrow;
+        }
+        zip(&mut self.chunks, rhs.chunks).fold(borrow, |mut borrow, (chunk_l, chunk_r)| {
+            (*chunk_l, borrow) = chunk_l.borrowing_sub(chunk_r, borrow);
+            borrow
+        })
+    }
+    pub fn overflowing_sub_in_place(&mut self, rhs: Self) -> bool {
+        self.borrowing_sub_in_place(rhs, false)
+    }
+    pub fn borrowing_sub(mut self, rhs: Self, borrow: bool) -> (Self, bool) {
+        let borrow = self.borrowing_sub_in_place(rhs, borrow);
+        (self, borrow)
+    }
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        self.borrowing_sub(rhs, false)
+    }
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        let result = self.overflowing_sub(rhs);
+        (!result.1).then_some(result.0)
+    }
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        self.borrowing_sub(rhs, false).0
+    }
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).unwrap_or(Self::MIN)
+    }
     pub fn split_overflowing_shl_in_place(
         &mut self,
         chunk_offset: usize,
@@ -105,7 +263,7 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
         let overflow = chunk_offset >= W;
         let chunk_offset = chunk_offset % W;
         assert!(bit_offset.is_valid());
-        split_shr_chunks(&mut self.chunks, chunk_offset, bit_offset);
+        split_shr_chunks(&mut self.chunks, chunk_offset, bit_offset, Chunk::ZERO);
         overflow
     }
     pub fn overflowing_shl_in_place<Total: TotalBitCounter<Chunk>>(&mut self, rhs: Total) -> bool {
@@ -131,7 +289,7 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
         self.split_overflowing_shr_in_place(chunk_offset, bit_offset);
     }
     pub fn wrapping_shl_in_place<Total: TotalBitCounter<Chunk>>(&mut self, rhs: Total) {
-        self.overflowing_shr_in_place(rhs);
+        self.overflowing_shl_in_place(rhs);
     }
     pub fn wrapping_shr_in_place<Total: TotalBitCounter<Chunk>>(&mut self, rhs: Total) {
         self.overflowing_shr_in_place(rhs);
@@ -178,7 +336,7 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
         bit_offset: Chunk::BitCounter,
     ) -> Option<Self> {
         let result = self.split_overflowing_shl(chunk_offset, bit_offset);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
     }
     pub fn split_checked_shr(
         self,
@@ -186,15 +344,15 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
         bit_offset: Chunk::BitCounter,
     ) -> Option<Self> {
         let result = self.split_overflowing_shr(chunk_offset, bit_offset);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
     }
     pub fn checked_shl<Total: TotalBitCounter<Chunk>>(self, rhs: Total) -> Option<Self> {
         let result = self.overflowing_shl(rhs);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
     }
     pub fn checked_shr<Total: TotalBitCounter<Chunk>>(self, rhs: Total) -> Option<Self> {
         let result = self.overflowing_shr(rhs);
-        result.1.then_some(result.0)
+        (!result.1).then_some(result.0)
     }
     pub fn split_rotate_left_in_place(
         &mut self,
@@ -202,7 +360,7 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
         bit_offset: Chunk::BitCounter,
     ) {
         assert!(bit_offset.is_valid());
-        split_rotate_left_chunks(&mut self.chunks, chunk_offset, bit_offset);
+        split_rotate_left_chunks(&mut self.chunks, chunk_offset % W, bit_offset);
     }
     pub fn split_rotate_right_in_place(
         &mut self,
@@ -210,7 +368,7 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
         bit_offset: Chunk::BitCounter,
     ) {
         assert!(bit_offset.is_valid());
-        split_rotate_right_chunks(&mut self.chunks, chunk_offset, bit_offset);
+        split_rotate_right_chunks(&mut self.chunks, chunk_offset % W, bit_offset);
     }
     pub fn rotate_left_in_place<Total: TotalBitCounter<Chunk>>(&mut self, n: Total) {
         let (chunk_offset, bit_offset) = n.split();
@@ -240,8 +398,15 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
         self.rotate_right_in_place(n);
         self
     }
+    pub fn swap_chunks(mut self) -> Self {
+        self.chunks.reverse();
+        self
+    }
     pub fn swap_bytes(mut self) -> Self {
         self.chunks.reverse();
+        for chunk in &mut self.chunks {
+            *chunk = chunk.swap_bytes();
+        }
         self
     }
     pub fn swap_bits(mut self) -> Self {
@@ -251,11 +416,128 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
         }
         self
     }
+    pub fn to_be(self) -> Self {
+        if cfg!(target_endian = "big") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+    pub fn to_le(self) -> Self {
+        if cfg!(target_endian = "little") {
+            self
+        } else {
+            self.swap_bytes()
+        }
+    }
+    pub fn from_be(value: Self) -> Self {
+        value.to_be()
+    }
+    pub fn from_le(value: Self) -> Self {
+        value.to_le()
+    }
+    pub fn to_le_bytes<const N: usize>(self) -> [u8; N]
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        const {
+            assert!(
+                Chunk::BITS % 8 == 0 && W * (Chunk::BITS / 8) as usize == N,
+                "byte array length must match bit width"
+            )
+        };
+        chunks_to_le_bytes(self.chunks)
+    }
+    pub fn to_be_bytes<const N: usize>(self) -> [u8; N]
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        let mut bytes = self.to_le_bytes();
+        bytes.reverse();
+        bytes
+    }
+    pub fn to_ne_bytes<const N: usize>(self) -> [u8; N]
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        if cfg!(target_endian = "big") {
+            self.to_be_bytes()
+        } else {
+            self.to_le_bytes()
+        }
+    }
+    pub fn from_le_bytes<const N: usize>(bytes: [u8; N]) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        const {
+            assert!(
+                Chunk::BITS % 8 == 0 && W * (Chunk::BITS / 8) as usize == N,
+                "byte array length must match bit width"
+            )
+        };
+        U {
+            chunks: chunks_from_le_bytes(bytes.into_iter()),
+        }
+    }
+    pub fn from_be_bytes<const N: usize>(mut bytes: [u8; N]) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        bytes.reverse();
+        Self::from_le_bytes(bytes)
+    }
+    pub fn from_ne_bytes<const N: usize>(bytes: [u8; N]) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        if cfg!(target_endian = "big") {
+            Self::from_be_bytes(bytes)
+        } else {
+            Self::from_le_bytes(bytes)
+        }
+    }
+    pub fn from_le_slice(bytes: &[u8]) -> Option<Self>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        const {
+            assert!(
+                Chunk::BITS % 8 == 0,
+                "chunk width must be a whole number of bytes"
+            )
+        };
+        (bytes.len() <= W * (Chunk::BITS / 8) as usize).then(|| U {
+            chunks: chunks_from_le_bytes(bytes.iter().copied().chain(repeat(0))),
+        })
+    }
+    pub fn from_be_slice(bytes: &[u8]) -> Option<Self>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        const {
+            assert!(
+                Chunk::BITS % 8 == 0,
+                "chunk width must be a whole number of bytes"
+            )
+        };
+        (bytes.len() <= W * (Chunk::BITS / 8) as usize).then(|| U {
+            chunks: chunks_from_le_bytes(bytes.iter().rev().copied().chain(repeat(0))),
+        })
+    }
     pub fn overflowing_add_signed(self, rhs: I<W, Chunk>) -> (Self, bool) {
         let negative = rhs < I::ZERO;
         let (result, overflow) = self.overflowing_add(rhs.reinterpret_unsigned());
         (result, overflow ^ negative)
     }
+    pub fn saturating_add_signed(self, rhs: I<W, Chunk>) -> Self {
+        let negative = rhs < I::ZERO;
+        match self.overflowing_add_signed(rhs) {
+            (result, false) => result,
+            (_, true) if negative => Self::MIN,
+            (_, true) => Self::MAX,
+        }
+    }
     pub fn carrying_mul(self, rhs: Self, carry: Self) -> (Self, Self) {
         let mut hi = [Chunk::ZERO; W];
         let mut lo = [Chunk::ZERO; W];
@@ -281,6 +563,306 @@ impl<const W: usize, Chunk: ChunkType> U<W, Chunk> {
     pub fn widening_mul(self, rhs: Self) -> (Self, Self) {
         self.carrying_mul(rhs, Self::ZERO)
     }
+    pub fn overflowing_mul_in_place(&mut self, rhs: Self) -> bool {
+        let (lo, hi) = self.widening_mul(rhs);
+        *self = lo;
+        hi != Self::ZERO
+    }
+    pub fn overflowing_mul(mut self, rhs: Self) -> (Self, bool) {
+        let overflow = self.overflowing_mul_in_place(rhs);
+        (self, overflow)
+    }
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let result = self.overflowing_mul(rhs);
+        (!result.1).then_some(result.0)
+    }
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).unwrap_or(Self::MAX)
+    }
+    pub fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+        let (mut base, mut result, mut overflow) = (self, Self::ONE, false);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                overflow |= result.overflowing_mul_in_place(base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                overflow |= base.overflowing_mul_in_place(base);
+            }
+        }
+        (result, overflow)
+    }
+    pub fn checked_pow(self, mut exp: u32) -> Option<Self> {
+        let (mut base, mut result) = (self, Self::ONE);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.checked_mul(base)?;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.checked_mul(base)?;
+            }
+        }
+        Some(result)
+    }
+    pub fn wrapping_pow(self, exp: u32) -> Self {
+        self.overflowing_pow(exp).0
+    }
+    pub fn saturating_pow(self, mut exp: u32) -> Self {
+        let (mut base, mut result) = (self, Self::ONE);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                let Some(product) = result.checked_mul(base) else {
+                    return Self::MAX;
+                };
+                result = product;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                let Some(square) = base.checked_mul(base) else {
+                    return Self::MAX;
+                };
+                base = square;
+            }
+        }
+        result
+    }
+    #[cfg(overflow_checks)]
+    pub fn pow(self, exp: u32) -> Self {
+        self.checked_pow(exp)
+            .expect("attempt to multiply with overflow")
+    }
+    #[cfg(not(overflow_checks))]
+    pub fn pow(self, exp: u32) -> Self {
+        self.wrapping_pow(exp)
+    }
+    pub fn is_power_of_two(self) -> bool {
+        self.count_ones::<BitCount<Chunk>>() == BitCount::new(0, Chunk::ONE.count_ones())
+    }
+    pub fn checked_next_power_of_two(self) -> Option<Self> {
+        if self <= Self::ONE {
+            return Some(Self::ONE);
+        }
+        let zeros = self
+            .wrapping_sub(Self::ONE)
+            .leading_zeros::<BitCount<Chunk>>()?;
+        (zeros != BitCount::ZERO).then(|| Self::MAX.wrapping_shr(zeros).wrapping_add(Self::ONE))
+    }
+    #[cfg(overflow_checks)]
+    pub fn next_power_of_two(self) -> Self {
+        self.checked_next_power_of_two()
+            .expect("attempt to add with overflow")
+    }
+    #[cfg(not(overflow_checks))]
+    pub fn next_power_of_two(self) -> Self {
+        self.checked_next_power_of_two().unwrap_or(Self::ZERO)
+    }
+    pub fn isqrt(self) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        self.nth_root(2)
+    }
+    pub fn icbrt(self) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        self.nth_root(3)
+    }
+    pub fn nth_root(self, n: u32) -> Self
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        assert!(n != 0, "attempt to take the zeroth root");
+        let zeros: usize = self.leading_zeros().unwrap();
+        let bits = W * Chunk::BITS as usize - zeros;
+        if n == 1 || self <= Self::ONE {
+            return self;
+        }
+        if n as usize >= bits {
+            return Self::ONE;
+        }
+        let mut root = Self::ONE.wrapping_shl(bits.div_ceil(n as usize));
+        loop {
+            let quotient = root
+                .checked_pow(n - 1)
+                .map_or(Self::ZERO, |power| self / power);
+            let next = (Self::wrapping_from(n - 1) * root + quotient) / Self::wrapping_from(n);
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+    pub fn is_perfect_square(self) -> bool
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        let root = self.isqrt();
+        root * root == self
+    }
+    pub fn is_perfect_power(self) -> bool
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        if self <= Self::ONE {
+            return true;
+        }
+        (2..)
+            .map_while(|n| {
+                let root = self.nth_root(n);
+                (root > Self::ONE).then(|| root.checked_pow(n) == Some(self))
+            })
+            .any(|exact| exact)
+    }
+    pub fn checked_ilog2(self) -> Option<u32>
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        let zeros: u32 = self.leading_zeros()?;
+        (self != Self::ZERO).then(|| Self::bits::<u32>().unwrap() - 1 - zeros)
+    }
+    pub fn checked_ilog(self, base: Self) -> Option<u32> {
+        if self == Self::ZERO || base <= Self::ONE {
+            return None;
+        }
+        Some(self.ilog_unchecked(base))
+    }
+    pub fn checked_ilog10(self) -> Option<u32> {
+        if self == Self::ZERO {
+            return None;
+        }
+        // Only 10^(2^k) for k <= top can fit in W chunks, so the table of
+        // powers of ten has top + 1 entries whatever the value of self.
+        let bits = W * chunk_bits_u128::<Chunk>() as usize;
+        let top = (max_digits(bits, 10) - 1).ilog2();
+        Some(self.ilog10_from(Self::wrapping_from(10), 0, top).1)
+    }
+    fn ilog10_from(self, power: Self, k: u32, top: u32) -> (Self, u32) {
+        let (floor, log) = match power.checked_mul(power) {
+            Some(square) if k < top => self.ilog10_from(square, k + 1, top),
+            _ => (Self::ONE, 0),
+        };
+        match floor.checked_mul(power) {
+            Some(next) if next <= self => (next, log | 1 << k),
+            _ => (floor, log),
+        }
+    }
+    fn ilog_unchecked(self, base: Self) -> u32 {
+        // powers[k] = base^(2^k); the logarithm is below the bit width, so 32
+        // entries are enough for any W.
+        let mut powers = [Self::ZERO; 32];
+        let (mut len, mut power) = (0, Some(base));
+        while let Some(current) = power.filter(|&power| power <= self) {
+            powers[len] = current;
+            len += 1;
+            power = current.checked_mul(current);
+        }
+        let (mut floor, mut log) = (Self::ONE, 0);
+        for (index, &power) in powers[..len].iter().enumerate().rev() {
+            if let Some(next) = floor.checked_mul(power).filter(|&next| next <= self) {
+                floor = next;
+                log |= 1 << index;
+            }
+        }
+        log
+    }
+    pub fn ilog2(self) -> u32
+    where
+        Chunk: ChunkType<BitCounter = u32>,
+    {
+        self.checked_ilog2()
+            .expect("argument of integer logarithm must be positive")
+    }
+    pub fn ilog(self, base: Self) -> u32 {
+        assert!(
+            base > Self::ONE,
+            "base of integer logarithm must be at least 2"
+        );
+        self.checked_ilog(base)
+            .expect("argument of integer logarithm must be positive")
+    }
+    pub fn ilog10(self) -> u32 {
+        self.checked_ilog10()
+            .expect("argument of integer logarithm must be positive")
+    }
+    pub fn gcd(self, rhs: Self) -> Self {
+        if self == Self::ZERO {
+            return rhs;
+        }
+        if rhs == Self::ZERO {
+            return self;
+        }
+        let (chunk_offset, bit_offset) = (self | rhs)
+            .trailing_zeros::<BitCount<Chunk>>()
+            .unwrap()
+            .split();
+        let (mut lhs, mut rhs) = (self, rhs);
+        shr_trailing_zeros_chunks(&mut lhs.chunks);
+        loop {
+            shr_trailing_zeros_chunks(&mut rhs.chunks);
+            if lhs > rhs {
+                swap(&mut lhs, &mut rhs);
+            }
+            rhs.overflowing_sub_in_place(lhs);
+            if rhs == Self::ZERO {
+                return lhs.split_wrapping_shl(chunk_offset, bit_offset);
+            }
+        }
+    }
+    pub fn checked_lcm(self, rhs: Self) -> Option<Self> {
+        if self == Self::ZERO || rhs == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+        let gcd = self.gcd(rhs);
+        (self / gcd).checked_mul(rhs)
+    }
+    pub fn lcm(self, rhs: Self) -> Self {
+        if self == Self::ZERO || rhs == Self::ZERO {
+            return Self::ZERO;
+        }
+        let gcd = self.gcd(rhs);
+        self / gcd * rhs
+    }
+    pub fn extended_gcd(self, rhs: Self) -> (Self, I<W, Chunk>, I<W, Chunk>) {
+        let (mut old_r, mut r) = (self, rhs);
+        let (mut old_s, mut s) = (I::ONE, I::ZERO);
+        let (mut old_t, mut t) = (I::ZERO, I::ONE);
+        while r != Self::ZERO {
+            let (quotient, remainder) = old_r.div_rem(r).unwrap();
+            let quotient = quotient.reinterpret_signed();
+            old_r = replace(&mut r, remainder);
+            let next_s = old_s.wrapping_sub(quotient.clone().wrapping_mul(s.clone()));
+            old_s = replace(&mut s, next_s);
+            let next_t = old_t.wrapping_sub(quotient.wrapping_mul(t.clone()));
+            old_t = replace(&mut t, next_t);
+        }
+        (old_r, old_s, old_t)
+    }
+    pub fn div_rem(self, rhs: Self) -> Option<(Self, Self)> {
+        let (quotient, remainder) = div_rem_chunks(self.chunks, rhs.chunks)?;
+        Some((U { chunks: quotient }, U { chunks: remainder }))
+    }
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+    pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self / rhs
+    }
+    pub fn div_ceil(self, rhs: Self) -> Self {
+        let (quotient, remainder) = self.div_rem(rhs).expect("attempt to divide by zero");
+        if remainder == Self::ZERO {
+            quotient
+        } else {
+            quotient.wrapping_add(Self::ONE)
+        }
+    }
 }
 
 impl<const W: usize, Chunk: ChunkType> PartialOrd for U<W, Chunk> {
@@ -291,7 +873,10 @@ impl<const W: usize, Chunk: ChunkType> PartialOrd for U<W, Chunk> {
 
 impl<const W: usize, Chunk: ChunkType> Ord for U<W, Chunk> {
     fn cmp(&self, rhs: &Self) -> Ordering {
-        self.chunks.into_iter().cmp(rhs.chunks)
+        self.chunks
+            .into_iter()
+            .rev()
+            .cmp(rhs.chunks.into_iter().rev())
     }
 }
 
@@ -311,7 +896,7 @@ impl<const W: usize, Chunk: ChunkType> AddAssign for U<W, Chunk> {
     #[cfg(overflow_checks)]
     fn add_assign(&mut self, rhs: Self) {
         assert!(
-            self.overflowing_add_in_place(rhs),
+            !self.overflowing_add_in_place(rhs),
             "attempt to add with overflow"
         );
     }
@@ -321,6 +906,91 @@ impl<const W: usize, Chunk: ChunkType> AddAssign for U<W, Chunk> {
     }
 }
 
+impl<const W: usize, Chunk: ChunkType> Sub for U<W, Chunk> {
+    type Output = Self;
+    #[cfg(overflow_checks)]
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+    #[cfg(not(overflow_checks))]
+    fn sub(self, rhs: Self) -> Self {
+        self.overflowing_sub(rhs).0
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> SubAssign for U<W, Chunk> {
+    #[cfg(overflow_checks)]
+    fn sub_assign(&mut self, rhs: Self) {
+        assert!(
+            !self.overflowing_sub_in_place(rhs),
+            "attempt to subtract with overflow"
+        );
+    }
+    #[cfg(not(overflow_checks))]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.overflowing_sub_in_place(rhs);
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Mul for U<W, Chunk> {
+    type Output = Self;
+    #[cfg(overflow_checks)]
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+    #[cfg(not(overflow_checks))]
+    fn mul(self, rhs: Self) -> Self {
+        self.wrapping_mul(rhs)
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> MulAssign for U<W, Chunk> {
+    #[cfg(overflow_checks)]
+    fn mul_assign(&mut self, rhs: Self) {
+        assert!(
+            !self.overflowing_mul_in_place(rhs),
+            "attempt to multiply with overflow"
+        );
+    }
+    #[cfg(not(overflow_checks))]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.overflowing_mul_in_place(rhs);
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Div for U<W, Chunk> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        self.checked_div(rhs).expect("attempt to divide by zero")
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> DivAssign for U<W, Chunk> {
+    fn div_assign(&mut self, rhs: Self) {
+        (self.chunks, _) =
+            div_rem_chunks(self.chunks, rhs.chunks).expect("attempt to divide by zero");
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> Rem for U<W, Chunk> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self.checked_rem(rhs)
+            .expect("attempt to calculate the remainder with a divisor of zero")
+    }
+}
+
+impl<const W: usize, Chunk: ChunkType> RemAssign for U<W, Chunk> {
+    fn rem_assign(&mut self, rhs: Self) {
+        (_, self.chunks) = div_rem_chunks(self.chunks, rhs.chunks)
+            .expect("attempt to calculate the remainder with a divisor of zero");
+    }
+}
+
+impl_bitwise_ops!(U);
+
 impl<const W: usize, Chunk: ChunkType, Total: TotalBitCounter<Chunk>> Shl<Total> for U<W, Chunk> {
     type Output = Self;
     #[cfg(overflow_checks)]
@@ -353,7 +1023,7 @@ impl<const W: usize, Chunk: ChunkType, Total: TotalBitCounter<Chunk>> ShlAssign<
     #[cfg(overflow_checks)]
     fn shl_assign(&mut self, rhs: Total) {
         assert!(
-            self.overflowing_shl_in_place(rhs),
+            !self.overflowing_shl_in_place(rhs),
             "attempt to shift left with overflow"
         )
     }
@@ -369,12 +1039,187 @@ impl<const W: usize, Chunk: ChunkType, Total: TotalBitCounter<Chunk>> ShrAssign<
     #[cfg(overflow_checks)]
     fn shr_assign(&mut self, rhs: Total) {
         assert!(
-            self.overflowing_shr_in_place(rhs),
+            !self.overflowing_shr_in_place(rhs),
             "attempt to shift right with overflow"
         )
     }
     #[cfg(not(overflow_checks))]
-    fn shl_assign(&mut self, rhs: Total) {
+    fn shr_assign(&mut self, rhs: Total) {
         self.wrapping_shr_in_place(rhs);
     }
 }
+
+#[cfg(all(test, feature = "chunks_64"))]
+mod tests {
+    use super::U;
+    use crate::i::I;
+
+    type U2 = U<2, u64>;
+    type I2 = I<2, u64>;
+
+    fn u(value: u128) -> U2 {
+        U2::wrapping_from(value)
+    }
+
+    #[test]
+    fn pow() {
+        assert_eq!(u(3).checked_pow(80), Some(u(3u128.pow(80))));
+        assert_eq!(u(3).checked_pow(81), None);
+        assert_eq!(u(3).saturating_pow(81), U2::MAX);
+        assert_eq!(u(3).overflowing_pow(81), (u(3u128.wrapping_pow(81)), true));
+        assert_eq!(u(0).checked_pow(0), Some(u(1)));
+        assert_eq!(u(2).saturating_pow(127), u(1 << 127));
+        assert_eq!(u(2).saturating_pow(128), U2::MAX);
+    }
+
+    #[test]
+    fn power_of_two() {
+        assert!(!u(0).is_power_of_two());
+        assert!(u(1).is_power_of_two());
+        assert!(u(1 << 127).is_power_of_two());
+        assert!(!U2::MAX.is_power_of_two());
+        let half = (U2::MAX >> 1u32).wrapping_add(u(1));
+        assert_eq!(u(0).checked_next_power_of_two(), Some(u(1)));
+        assert_eq!(u(5).checked_next_power_of_two(), Some(u(8)));
+        assert_eq!(half.checked_next_power_of_two(), Some(half));
+        assert_eq!(half.wrapping_add(u(1)).checked_next_power_of_two(), None);
+        assert_eq!(U2::MAX.checked_next_power_of_two(), None);
+    }
+
+    #[test]
+    fn roots() {
+        assert_eq!(u(0).isqrt(), u(0));
+        assert_eq!(u(99).isqrt(), u(9));
+        assert_eq!(u(100).isqrt(), u(10));
+        assert_eq!(U2::MAX.isqrt(), u(u64::MAX as u128));
+        assert_eq!(U2::MAX.icbrt(), u(6981463658331));
+        assert_eq!(u(1 << 120).nth_root(5), u(1 << 24));
+        assert_eq!(u((1 << 120) - 1).nth_root(5), u((1 << 24) - 1));
+        assert_eq!(U2::MAX.nth_root(127), u(2));
+        assert_eq!(U2::MAX.nth_root(128), u(1));
+        assert_eq!(U2::MAX.nth_root(1), U2::MAX);
+        assert!(u(1 << 126).is_perfect_square());
+        assert!(!u((1 << 126) + 1).is_perfect_square());
+    }
+
+    #[test]
+    fn perfect_power() {
+        assert!(u(0).is_perfect_power());
+        assert!(u(1).is_perfect_power());
+        assert!(u(1 << 127).is_perfect_power());
+        assert!(u(3u128.pow(80)).is_perfect_power());
+        assert!(u(6u128.pow(7)).is_perfect_power());
+        assert!(!u(6u128.pow(7) + 1).is_perfect_power());
+        assert!(!u(2).is_perfect_power());
+        assert!(!U2::MAX.is_perfect_power());
+    }
+
+    #[test]
+    fn ilog() {
+        assert_eq!(u(0).checked_ilog10(), None);
+        assert_eq!(u(1).checked_ilog10(), Some(0));
+        let mut power = 1u128;
+        for k in 1..=38 {
+            power *= 10;
+            assert_eq!(u(power - 1).checked_ilog10(), Some(k - 1));
+            assert_eq!(u(power).checked_ilog10(), Some(k));
+            assert_eq!(u(power + 1).checked_ilog10(), Some(k));
+        }
+        assert_eq!(U2::MAX.ilog10(), 38);
+        assert_eq!(U2::MAX.ilog(u(3)), 80);
+        assert_eq!(u(3u128.pow(80) - 1).ilog(u(3)), 79);
+        assert_eq!(U2::MAX.ilog2(), 127);
+        assert_eq!(u(1).checked_ilog(u(1)), None);
+    }
+
+    #[cfg(feature = "chunks_8")]
+    #[test]
+    fn ilog10_narrow_chunks() {
+        for value in 1..=u8::MAX {
+            assert_eq!(U::<1, u8>::wrapping_from(value).ilog10(), value.ilog10());
+        }
+        for value in (1u32..1 << 24)
+            .step_by(997)
+            .chain([999_999, 1_000_000, (1 << 24) - 1])
+        {
+            assert_eq!(U::<3, u8>::wrapping_from(value).ilog10(), value.ilog10());
+        }
+    }
+
+    #[test]
+    fn gcd() {
+        assert_eq!(u(0).gcd(u(0)), u(0));
+        assert_eq!(u(0).gcd(u(12)), u(12));
+        assert_eq!(u(12).gcd(u(0)), u(12));
+        assert_eq!(u(48).gcd(u(180)), u(12));
+        assert_eq!(u(1 << 100).gcd(u(3 << 64)), u(1 << 64));
+        assert_eq!(U2::MAX.gcd(u(u64::MAX as u128)), u(u64::MAX as u128));
+        assert_eq!(u(4).lcm(u(6)), u(12));
+        assert_eq!(U2::MAX.checked_lcm(u(2)), None);
+    }
+
+    #[test]
+    fn extended_gcd() {
+        for (a, b) in [
+            (240, 46),
+            (0, 7),
+            (7, 0),
+            (1 << 100, (1 << 90) + 3),
+            (17, 17),
+        ] {
+            let (gcd, x, y) = u(a).extended_gcd(u(b));
+            assert_eq!(gcd, u(a).gcd(u(b)));
+            assert_eq!(
+                I2::wrapping_from(a).wrapping_mul(x) + I2::wrapping_from(b).wrapping_mul(y),
+                gcd.reinterpret_signed()
+            );
+        }
+    }
+
+    #[test]
+    fn bytes() {
+        for value in [
+            0,
+            1,
+            0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10,
+            u128::MAX - 1,
+        ] {
+            assert_eq!(u(value).swap_bytes(), u(value.swap_bytes()));
+            assert_eq!(u(value).swap_chunks(), u(value.rotate_left(64)));
+            assert_eq!(u(value).to_be(), u(value.to_be()));
+            assert_eq!(u(value).to_le(), u(value.to_le()));
+            assert_eq!(U2::from_be(u(value)), u(u128::from_be(value)));
+            assert_eq!(u(value).to_le_bytes(), value.to_le_bytes());
+            assert_eq!(u(value).to_be_bytes(), value.to_be_bytes());
+            assert_eq!(u(value).to_ne_bytes(), value.to_ne_bytes());
+            assert_eq!(U2::from_le_bytes(value.to_le_bytes()), u(value));
+            assert_eq!(U2::from_be_bytes(value.to_be_bytes()), u(value));
+            assert_eq!(U2::from_ne_bytes(value.to_ne_bytes()), u(value));
+            assert_eq!(U2::from_le_slice(&value.to_le_bytes()), Some(u(value)));
+            assert_eq!(U2::from_be_slice(&value.to_be_bytes()), Some(u(value)));
+        }
+    }
+
+    #[test]
+    fn byte_slices() {
+        assert_eq!(U2::from_le_slice(&[]), Some(u(0)));
+        assert_eq!(U2::from_le_slice(&[0x34, 0x12]), Some(u(0x1234)));
+        assert_eq!(U2::from_be_slice(&[0x12, 0x34]), Some(u(0x1234)));
+        assert_eq!(U2::from_le_slice(&[0xff; 3]), Some(u(0xff_ffff)));
+        assert_eq!(U2::from_le_slice(&[0; 17]), None);
+        assert_eq!(U2::from_be_slice(&[0; 17]), None);
+    }
+
+    #[cfg(feature = "chunks_8")]
+    #[test]
+    fn bytes_narrow_chunks() {
+        let value = 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128;
+        let narrow = U::<16, u8>::wrapping_from(value);
+        assert_eq!(narrow.swap_bytes(), U::wrapping_from(value.swap_bytes()));
+        assert_eq!(narrow.to_be_bytes(), value.to_be_bytes());
+        assert_eq!(
+            U::<16, u8>::from_be_slice(&[0x12, 0x34]),
+            Some(U::wrapping_from(0x1234u16))
+        );
+    }
+}